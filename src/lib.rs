@@ -1 +1,10 @@
+#![cfg_attr(not(test), no_std)]
+// existing tests predate these lints
+#![cfg_attr(test, allow(clippy::clone_on_copy, clippy::useless_conversion, clippy::empty_line_after_outer_attr))]
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod linalg;
@@ -1,2 +1,8 @@
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod krylov;
 pub mod matrix;
-pub mod vector;
\ No newline at end of file
+pub mod operator;
+pub mod scalar;
+pub mod simd;
+pub mod vector;
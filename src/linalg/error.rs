@@ -0,0 +1,29 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The vectors are linearly dependent, only `rank` of them are independent.
+    RankDeficient { rank: usize },
+    /// The matrix is singular to working precision.
+    Singular,
+    /// An iterative method did not converge within `iterations` steps.
+    NoConvergence { iterations: usize },
+    /// An exact integer computation overflowed the element type.
+    Overflow,
+    /// A NaN or infinite value appeared in the input or the result.
+    NonFinite,
+}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinalgError::RankDeficient { rank } => write!(f, "rank deficient, rank is {}", rank),
+            LinalgError::Singular => write!(f, "matrix is singular"),
+            LinalgError::NoConvergence { iterations } => write!(f, "no convergence after {} iterations", iterations),
+            LinalgError::Overflow => write!(f, "integer overflow"),
+            LinalgError::NonFinite => write!(f, "non-finite value"),
+        }
+    }
+}
+
+impl core::error::Error for LinalgError {}
@@ -0,0 +1,289 @@
+use crate::linalg::matrix::elementary::Givens;
+use crate::linalg::operator::LinearOperator;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Stopping criteria shared by the Krylov solvers. Iteration stops once
+/// `‖b - Ax‖ <= tol ‖b‖` or after `max_iterations` iterations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KrylovOptions<T> {
+    pub tol: T,
+    pub max_iterations: usize,
+}
+
+impl<T> KrylovOptions<T> {
+    pub fn new(tol: T, max_iterations: usize) -> Self {
+        KrylovOptions { tol, max_iterations }
+    }
+}
+
+/// Outcome of a Krylov solve. `residuals` holds the residual norm before the
+/// first and after every iteration.
+#[derive(Debug, PartialEq)]
+pub struct KrylovResult<T, const N: usize> {
+    pub x: Vector<T, N>,
+    pub iterations: usize,
+    pub residuals: Vec<T>,
+    pub converged: bool,
+}
+
+/// Conjugate gradient method for symmetric positive definite `A`.
+pub fn cg<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: &Vector<T, N>,
+    options: &KrylovOptions<T>,
+) -> KrylovResult<T, N>
+where
+    T: Float,
+    A: LinearOperator<T, N, N>,
+{
+    let target = options.tol * b.norm();
+    let mut x = *x0;
+    let mut r = *b - a.apply(&x);
+    let mut p = r;
+    let mut rr = r * r;
+    let mut residuals = vec![T::sqrt(rr)];
+    let mut iterations = 0;
+
+    while T::sqrt(rr) > target && iterations < options.max_iterations {
+        let ap = a.apply(&p);
+        let pap = p * ap;
+        if pap == T::ZERO {
+            break;
+        }
+        let alpha = rr / pap;
+        x = x + p.mul_scal(alpha);
+        r = r - ap.mul_scal(alpha);
+        let next = r * r;
+        p = r + p.mul_scal(next / rr);
+        rr = next;
+        iterations += 1;
+        residuals.push(T::sqrt(rr));
+    }
+    KrylovResult { x, iterations, converged: T::sqrt(rr) <= target, residuals }
+}
+
+/// Stabilized biconjugate gradient method (BiCGSTAB) for general square `A`.
+///
+/// Each iteration applies the operator twice. A breakdown of the recurrence
+/// ends the iteration early with `converged` unset.
+pub fn bicgstab<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: &Vector<T, N>,
+    options: &KrylovOptions<T>,
+) -> KrylovResult<T, N>
+where
+    T: Float,
+    A: LinearOperator<T, N, N>,
+{
+    let target = options.tol * b.norm();
+    let mut x = *x0;
+    let mut r = *b - a.apply(&x);
+    let shadow = r;
+    let (mut rho, mut alpha, mut omega) = (T::ONE, T::ONE, T::ONE);
+    let mut v = Vector::from_value(T::ZERO);
+    let mut p = Vector::from_value(T::ZERO);
+    let mut norm = r.norm();
+    let mut residuals = vec![norm];
+    let mut iterations = 0;
+
+    while norm > target && iterations < options.max_iterations {
+        let next = shadow * r;
+        if next == T::ZERO || omega == T::ZERO {
+            break;
+        }
+        let beta = (next / rho) * (alpha / omega);
+        rho = next;
+        p = r + (p - v.mul_scal(omega)).mul_scal(beta);
+        v = a.apply(&p);
+        let sv = shadow * v;
+        if sv == T::ZERO {
+            break;
+        }
+        alpha = rho / sv;
+        let s = r - v.mul_scal(alpha);
+        iterations += 1;
+
+        if s.norm() <= target {
+            x = x + p.mul_scal(alpha);
+            r = s;
+        } else {
+            let t = a.apply(&s);
+            let tt = t * t;
+            omega = if tt == T::ZERO { T::ZERO } else { (t * s) / tt };
+            x = x + p.mul_scal(alpha) + s.mul_scal(omega);
+            r = s - t.mul_scal(omega);
+        }
+        norm = r.norm();
+        residuals.push(norm);
+    }
+    KrylovResult { x, iterations, converged: norm <= target, residuals }
+}
+
+/// Restarted GMRES for general square `A`, with a Krylov basis of at most
+/// `restart` vectors per cycle (clamped to `1..=N`).
+///
+/// The Arnoldi basis is built with modified Gram–Schmidt and the least
+/// squares problem is updated with Givens rotations, so the residual norm of
+/// every iteration is known without forming the iterate.
+pub fn gmres<T, A, const N: usize>(
+    a: &A,
+    b: &Vector<T, N>,
+    x0: &Vector<T, N>,
+    restart: usize,
+    options: &KrylovOptions<T>,
+) -> KrylovResult<T, N>
+where
+    T: Float,
+    A: LinearOperator<T, N, N>,
+{
+    let target = options.tol * b.norm();
+    let restart = restart.clamp(1, N.max(1));
+    let mut x = *x0;
+    let mut residuals = Vec::new();
+    let mut iterations = 0;
+
+    loop {
+        let r = *b - a.apply(&x);
+        let beta = r.norm();
+        if residuals.is_empty() {
+            residuals.push(beta);
+        }
+        if beta <= target || iterations >= options.max_iterations {
+            return KrylovResult { x, iterations, converged: beta <= target, residuals };
+        }
+
+        let mut basis = vec![r.mul_scal(T::ONE / beta)];
+        // columns of the rotated Hessenberg matrix, i.e. of R
+        let mut columns: Vec<Vec<T>> = Vec::new();
+        let mut rotations: Vec<Givens<T>> = Vec::new();
+        let mut g = vec![beta];
+
+        for j in 0..restart {
+            if iterations >= options.max_iterations {
+                break;
+            }
+            iterations += 1;
+
+            let mut w = a.apply(&basis[j]);
+            let mut h = Vec::with_capacity(j + 2);
+            for v in &basis {
+                let hij = w * *v;
+                w = w - v.mul_scal(hij);
+                h.push(hij);
+            }
+            let next = w.norm();
+            h.push(next);
+
+            for (i, rotation) in rotations.iter().enumerate() {
+                (h[i], h[i + 1]) = rotation.apply(h[i], h[i + 1]);
+            }
+            let rotation = Givens::new(h[j], h[j + 1]);
+            (h[j], h[j + 1]) = rotation.apply(h[j], h[j + 1]);
+            g.push(T::ZERO);
+            (g[j], g[j + 1]) = rotation.apply(g[j], g[j + 1]);
+
+            columns.push(h);
+            rotations.push(rotation);
+            residuals.push(g[j + 1].abs());
+            if g[j + 1].abs() <= target || next == T::ZERO {
+                break;
+            }
+            basis.push(w.mul_scal(T::ONE / next));
+        }
+
+        // back substitution for the coefficients of the basis vectors
+        let k = columns.len();
+        let mut y = vec![T::ZERO; k];
+        for i in (0..k).rev() {
+            let sum = (i + 1..k).fold(g[i], |acc, l| acc - columns[l][i] * y[l]);
+            y[i] = sum / columns[i][i];
+        }
+        for (v, &coefficient) in basis.iter().zip(&y) {
+            x = x + v.mul_scal(coefficient);
+        }
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::matrix::{special, Matrix};
+
+    fn options() -> KrylovOptions<f64> {
+        KrylovOptions::new(1e-10, 100)
+    }
+    fn nonsymmetric() -> Matrix<f64, 4, 4> {
+        Matrix::from([
+            [4.0, 1.0, 0.0, 2.0],
+            [-1.0, 3.0, 1.0, 0.0],
+            [0.5, 0.0, 5.0, -1.0],
+            [1.0, 2.0, 0.0, 6.0],
+        ])
+    }
+
+    #[test]
+    fn cg() {
+        let a: Matrix<f64, 6, 6> = special::second_difference();
+        let b = Vector::from_value(1.0);
+        let result = super::cg(&a, &b, &Vector::from_value(0.0), &options());
+        assert!(result.converged);
+        // exact in at most N steps
+        assert!(result.iterations <= 6);
+        assert_eq!(result.iterations + 1, result.residuals.len());
+        assert!((a.mul_vector(&result.x) - b).norm() < 1e-9);
+    }
+    #[test]
+    fn matrix_free() {
+        // tridiag(-1, 2, -1) applied without storing it
+        let laplacian = |x: &Vector<f64, 8>| {
+            Vector::from_fn(|i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < 8 { x[i + 1] } else { 0.0 };
+                2.0 * x[i] - left - right
+            })
+        };
+        let b = Vector::from_fn(|i| i as f64);
+        let result = super::cg(&laplacian, &b, &Vector::from_value(0.0), &options());
+        assert!(result.converged);
+        assert!((laplacian(&result.x) - b).norm() < 1e-9);
+    }
+    #[test]
+    fn bicgstab() {
+        let a = nonsymmetric();
+        let b = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let result = super::bicgstab(&a, &b, &Vector::from_value(0.0), &options());
+        assert!(result.converged);
+        assert!((a.mul_vector(&result.x) - b).norm() < 1e-9);
+    }
+    #[test]
+    fn gmres() {
+        let a = nonsymmetric();
+        let b = Vector::from([1.0, 2.0, 3.0, 4.0]);
+        let result = super::gmres(&a, &b, &Vector::from_value(0.0), 4, &options());
+        assert!(result.converged);
+        assert!(result.iterations <= 4);
+        assert!((a.mul_vector(&result.x) - b).norm() < 1e-9);
+
+        // restarted with a short basis
+        let result = super::gmres(&a, &b, &Vector::from_value(0.0), 2, &options());
+        assert!(result.converged);
+        assert!(result.residuals.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+        assert!((a.mul_vector(&result.x) - b).norm() < 1e-9);
+    }
+    #[test]
+    fn not_converged() {
+        let a: Matrix<f64, 6, 6> = special::second_difference();
+        let b = Vector::from_value(1.0);
+        let result = super::gmres(&a, &b, &Vector::from_value(0.0), 2, &KrylovOptions::new(1e-14, 3));
+        assert!(!result.converged);
+        assert_eq!(3, result.iterations);
+        assert_eq!(4, result.residuals.len());
+    }
+}
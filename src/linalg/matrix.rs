@@ -1,13 +1,41 @@
 pub mod operations;
 pub mod iterators;
 pub mod indexing;
+pub mod banded;
+pub mod block;
+pub mod constructors;
+pub mod echelon;
+pub mod elementary;
+pub mod exact;
+pub mod functions;
+pub mod hessenberg;
+pub mod const_ops;
+pub mod inverse;
+pub mod normal_form;
+pub mod orthogonal;
+#[cfg(feature = "std")]
+pub mod parallel;
+pub mod polynomial;
+pub mod power;
+pub mod pseudo_inverse;
+pub mod reshape;
+pub mod schur;
+pub mod simd;
+pub mod special;
+#[cfg(feature = "alloc")]
+pub mod strassen;
+pub mod sylvester;
+#[cfg(feature = "alloc")]
+pub mod symmetric;
+pub mod triangular;
 
-use std::mem::{self, MaybeUninit};
+use core::mem::{self, MaybeUninit};
 use iterators::{MatrixIter, MatrixIterMut};
 
 #[derive(Debug)]
 pub struct Matrix<T: Default, const R: usize, const C: usize>(pub [[T; C]; R]);
 impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
         let cells = {
             let mut data: [[mem::MaybeUninit<T>; C]; R] = unsafe {
@@ -24,7 +52,7 @@ impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
         }; 
         Matrix(cells) 
     }
-    pub fn from( data: [[T; C]; R] ) -> Self {
+    pub const fn from( data: [[T; C]; R] ) -> Self {
         Matrix(data)
     }
     pub fn size(&self) -> (usize, usize) {
@@ -51,16 +79,16 @@ impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
         }
         m
     }
-    pub fn iter(&self) -> MatrixIter<T, R, C> {
+    pub fn iter(&self) -> MatrixIter<'_, T, R, C> {
         MatrixIter::new(self)
     }
-    pub fn iter_mut(&mut self) -> MatrixIterMut<T, R, C> {
+    pub fn iter_mut(&mut self) -> MatrixIterMut<'_, T, R, C> {
         MatrixIterMut::new( self)
     }
-    pub fn rows(self) -> std::array::IntoIter<[T; C], R> {
+    pub fn rows(self) -> core::array::IntoIter<[T; C], R> {
         self.0.into_iter()
     }
-    pub fn cols(self) -> std::array::IntoIter<[T; R], C> {
+    pub fn cols(self) -> core::array::IntoIter<[T; R], C> {
         let mut_self = self;
         mut_self.transpose().0.into_iter()
     }
@@ -69,11 +97,7 @@ impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
 impl<T: Copy + Default, const R: usize, const C: usize> Copy for Matrix<T, R, C> {}
 impl<T: Copy + Default, const R: usize, const C: usize> Clone for Matrix<T, R, C> {
     fn clone(&self) -> Self {
-        let mut m: Matrix<T, R, C> = Matrix::new();
-        for ((i, j), &v) in self.iter().enumerate() {
-            m[(i, j)] = v;
-        }
-        m
+        *self
     }
 }
 
@@ -0,0 +1,223 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Mul};
+
+/// Square matrix with `KL` subdiagonals and `KU` superdiagonals, only the
+/// diagonals are stored.
+///
+/// `lower[k - 1][j]` holds `A[j + k][j]` and `upper[k - 1][i]` holds
+/// `A[i][i + k]`, the trailing `k` slots of each off-diagonal are unused.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandedMatrix<T, const N: usize, const KL: usize, const KU: usize> {
+    lower: [[T; N]; KL],
+    diag: [T; N],
+    upper: [[T; N]; KU],
+}
+
+impl<T: Default + Copy, const N: usize, const KL: usize, const KU: usize> BandedMatrix<T, N, KL, KU> {
+    pub fn new() -> Self {
+        BandedMatrix {
+            lower: [[T::default(); N]; KL],
+            diag: [T::default(); N],
+            upper: [[T::default(); N]; KU],
+        }
+    }
+    fn slot(&self, (row, col): (usize, usize)) -> Option<&T> {
+        if row >= N || col >= N {
+            None
+        } else if row == col {
+            Some(&self.diag[row])
+        } else if row > col && row - col <= KL {
+            Some(&self.lower[row - col - 1][col])
+        } else if col > row && col - row <= KU {
+            Some(&self.upper[col - row - 1][row])
+        } else {
+            None
+        }
+    }
+    /// The entry at `(row, col)`, zero outside the band.
+    pub fn get(&self, (row, col): (usize, usize)) -> T {
+        assert!(row < N && col < N, "index out of bounds");
+        self.slot((row, col)).copied().unwrap_or_default()
+    }
+    /// Panics when `(row, col)` lies outside the band.
+    pub fn set(&mut self, (row, col): (usize, usize), value: T) {
+        assert!(row < N && col < N, "index out of bounds");
+        if row == col {
+            self.diag[row] = value;
+        } else if row > col && row - col <= KL {
+            self.lower[row - col - 1][col] = value;
+        } else if col > row && col - row <= KU {
+            self.upper[col - row - 1][row] = value;
+        } else {
+            panic!("({}, {}) is outside the band", row, col);
+        }
+    }
+    /// Keeps the band of `m` and drops the rest.
+    pub fn from_matrix(m: &Matrix<T, N, N>) -> Self {
+        let mut b = BandedMatrix::new();
+        for ((row, col), &v) in m.iter().enumerate() {
+            if b.slot((row, col)).is_some() {
+                b.set((row, col), v);
+            }
+        }
+        b
+    }
+    pub fn to_matrix(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| self.get((row, col)))
+    }
+}
+
+impl<T: Default + Copy, const N: usize, const KL: usize, const KU: usize> Default for BandedMatrix<T, N, KL, KU> {
+    fn default() -> Self {
+        BandedMatrix::new()
+    }
+}
+
+impl<T: Default + Copy, const N: usize> BandedMatrix<T, N, 1, 1> {
+    /// Tridiagonal matrix with `A[i + 1][i] = sub[i]`, `A[i][i] = diag[i]` and
+    /// `A[i][i + 1] = sup[i]`. The last entries of `sub` and `sup` are ignored.
+    pub fn tridiagonal(sub: Vector<T, N>, diag: Vector<T, N>, sup: Vector<T, N>) -> Self {
+        let mut b = BandedMatrix::new();
+        for i in 0..N {
+            b.diag[i] = diag[i];
+            if i + 1 < N {
+                b.lower[0][i] = sub[i];
+                b.upper[0][i] = sup[i];
+            }
+        }
+        b
+    }
+}
+
+impl<T, const N: usize, const KL: usize, const KU: usize> BandedMatrix<T, N, KL, KU>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    pub fn mul_vector(&self, rhs: &Vector<T, N>) -> Vector<T, N> {
+        Vector::from_fn(|row| {
+            let first = row.saturating_sub(KL);
+            let last = (row + KU).min(N.saturating_sub(1));
+            (first..=last).fold(T::default(), |acc, col| acc + self.get((row, col)) * rhs[col])
+        })
+    }
+}
+
+impl<T, const N: usize, const KL: usize, const KU: usize> Mul<Vector<T, N>> for BandedMatrix<T, N, KL, KU>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    type Output = Vector<T, N>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Self::Output {
+        self.mul_vector(&rhs)
+    }
+}
+
+impl<T: Float, const N: usize, const KL: usize, const KU: usize> BandedMatrix<T, N, KL, KU> {
+    /// Solves `Ax = b` by banded Gaussian elimination.
+    ///
+    /// There is no pivoting, so that the fill-in stays inside the band. This
+    /// is stable for diagonally dominant and symmetric positive definite
+    /// matrices, a zero pivot is reported as `LinalgError::Singular`.
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let mut a = *self;
+        let mut x = *b;
+        for k in 0..N {
+            let pivot = a.diag[k];
+            if pivot == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            for row in k + 1..(k + KL + 1).min(N) {
+                let factor = a.get((row, k)) / pivot;
+                for col in k + 1..(k + KU + 1).min(N) {
+                    a.set((row, col), a.get((row, col)) - factor * a.get((k, col)));
+                }
+                x[row] = x[row] - factor * x[k];
+            }
+        }
+        for row in (0..N).rev() {
+            let sum = (row + 1..(row + KU + 1).min(N)).fold(x[row], |acc, col| acc - a.get((row, col)) * x[col]);
+            x[row] = sum / a.diag[row];
+        }
+        Ok(x)
+    }
+}
+
+impl<T: Float, const N: usize> BandedMatrix<T, N, 1, 1> {
+    /// Solves a tridiagonal system with the Thomas algorithm.
+    ///
+    /// Like `solve` there is no pivoting, a zero pivot is reported as
+    /// `LinalgError::Singular`.
+    pub fn solve_tridiagonal(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let mut c = [T::ZERO; N];
+        let mut x = *b;
+        for i in 0..N {
+            let sub = if i > 0 { self.lower[0][i - 1] } else { T::ZERO };
+            let prev = if i > 0 { c[i - 1] } else { T::ZERO };
+            let m = self.diag[i] - sub * prev;
+            if m == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            if i + 1 < N {
+                c[i] = self.upper[0][i] / m;
+            }
+            let prev_x = if i > 0 { x[i - 1] } else { T::ZERO };
+            x[i] = (x[i] - sub * prev_x) / m;
+        }
+        for i in (0..N.saturating_sub(1)).rev() {
+            x[i] = x[i] - c[i] * x[i + 1];
+        }
+        Ok(x)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::matrix::special::second_difference;
+
+    #[test]
+    fn dense_roundtrip() {
+        let m = Matrix::from([[1, 2, 3, 0], [4, 5, 6, 7], [0, 8, 9, 1], [0, 0, 2, 3]]);
+        let b: BandedMatrix<_, 4, 1, 2> = BandedMatrix::from_matrix(&m);
+        assert_eq!(m, b.to_matrix());
+        assert_eq!(0, b.get((3, 0)));
+    }
+    #[test]
+    fn mul_vector() {
+        let m = Matrix::from([[1, 2, 0], [4, 5, 6], [0, 8, 9]]);
+        let v = Vector::from([1, 2, 3]);
+        let b: BandedMatrix<_, 3, 1, 1> = BandedMatrix::from_matrix(&m);
+        assert_eq!(m * v, b * v);
+    }
+    #[test]
+    fn solve() {
+        let m = Matrix::from([[4.0, 1.0, 0.5, 0.0], [1.0, 5.0, 1.0, 0.5], [0.0, 1.0, 6.0, 1.0], [0.0, 0.0, 2.0, 7.0]]);
+        let x = Vector::from([1.0, -2.0, 3.0, 0.5]);
+        let b: BandedMatrix<_, 4, 1, 2> = BandedMatrix::from_matrix(&m);
+        let solved = b.solve(&(m * x)).unwrap();
+        assert!((solved - x).norm() < 1e-12);
+    }
+    #[test]
+    fn solve_tridiagonal() {
+        let m: Matrix<f64, 5, 5> = second_difference();
+        let b: BandedMatrix<_, 5, 1, 1> = BandedMatrix::from_matrix(&m);
+        let x = Vector::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let solved = b.solve_tridiagonal(&(m * x)).unwrap();
+        assert!((solved - x).norm() < 1e-12);
+        assert!((b.solve(&(m * x)).unwrap() - x).norm() < 1e-12);
+
+        let t = BandedMatrix::tridiagonal(Vector::from([-1.0; 5]), Vector::from([2.0; 5]), Vector::from([-1.0; 5]));
+        assert_eq!(b, t);
+    }
+    #[test]
+    fn solve_singular() {
+        let b = BandedMatrix::tridiagonal(Vector::from([1.0; 2]), Vector::from([0.0; 2]), Vector::from([1.0; 2]));
+        assert_eq!(Err(LinalgError::Singular), b.solve_tridiagonal(&Vector::from([1.0, 1.0])));
+    }
+}
@@ -0,0 +1,144 @@
+use super::Matrix;
+use core::mem;
+
+impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    // Copies row by row through the arrays, so blocks with no rows or no
+    // columns are fine.
+    fn put_block<const BR: usize, const BC: usize>(&mut self, block: Matrix<T, BR, BC>, (row, col): (usize, usize)) {
+        for (dst, src) in self.0[row..row + BR].iter_mut().zip(block.0) {
+            for (d, v) in dst[col..col + BC].iter_mut().zip(src) {
+                *d = v;
+            }
+        }
+    }
+    fn take_block<const BR: usize, const BC: usize>(&mut self, (row, col): (usize, usize)) -> Matrix<T, BR, BC> {
+        let mut block = Matrix::new();
+        for (dst, src) in block.0.iter_mut().zip(self.0[row..row + BR].iter_mut()) {
+            for (d, v) in dst.iter_mut().zip(src[col..col + BC].iter_mut()) {
+                *d = mem::take(v);
+            }
+        }
+        block
+    }
+
+    /// Places `a` and `b` side by side, `[A | B]`.
+    pub fn hstack<const C1: usize, const C2: usize>(a: Matrix<T, R, C1>, b: Matrix<T, R, C2>) -> Self {
+        const { assert!(C1 + C2 == C, "hstack: C1 + C2 must equal C") };
+        let mut m = Matrix::new();
+        m.put_block(a, (0, 0));
+        m.put_block(b, (0, C1));
+        m
+    }
+    /// Places `a` on top of `b`.
+    pub fn vstack<const R1: usize, const R2: usize>(a: Matrix<T, R1, C>, b: Matrix<T, R2, C>) -> Self {
+        const { assert!(R1 + R2 == R, "vstack: R1 + R2 must equal R") };
+        let mut m = Matrix::new();
+        m.put_block(a, (0, 0));
+        m.put_block(b, (R1, 0));
+        m
+    }
+    /// Assembles the block matrix `[[A, B], [C, D]]`.
+    pub fn from_blocks<const R1: usize, const R2: usize, const C1: usize, const C2: usize>(
+        a: Matrix<T, R1, C1>,
+        b: Matrix<T, R1, C2>,
+        c: Matrix<T, R2, C1>,
+        d: Matrix<T, R2, C2>,
+    ) -> Self {
+        const { assert!(R1 + R2 == R && C1 + C2 == C, "from_blocks: block sizes must add up to R x C") };
+        let mut m = Matrix::new();
+        m.put_block(a, (0, 0));
+        m.put_block(b, (0, C1));
+        m.put_block(c, (R1, 0));
+        m.put_block(d, (R1, C1));
+        m
+    }
+
+    /// Inverse of `hstack`.
+    pub fn hsplit<const C1: usize, const C2: usize>(self) -> (Matrix<T, R, C1>, Matrix<T, R, C2>) {
+        const { assert!(C1 + C2 == C, "hsplit: C1 + C2 must equal C") };
+        let mut m = self;
+        (m.take_block((0, 0)), m.take_block((0, C1)))
+    }
+    /// Inverse of `vstack`.
+    pub fn vsplit<const R1: usize, const R2: usize>(self) -> (Matrix<T, R1, C>, Matrix<T, R2, C>) {
+        const { assert!(R1 + R2 == R, "vsplit: R1 + R2 must equal R") };
+        let mut m = self;
+        (m.take_block((0, 0)), m.take_block((R1, 0)))
+    }
+    /// Inverse of `from_blocks`, returns the blocks `(A, B, C, D)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_blocks<const R1: usize, const R2: usize, const C1: usize, const C2: usize>(
+        self,
+    ) -> (Matrix<T, R1, C1>, Matrix<T, R1, C2>, Matrix<T, R2, C1>, Matrix<T, R2, C2>) {
+        const { assert!(R1 + R2 == R && C1 + C2 == C, "into_blocks: block sizes must add up to R x C") };
+        let mut m = self;
+        (
+            m.take_block((0, 0)),
+            m.take_block((0, C1)),
+            m.take_block((R1, 0)),
+            m.take_block((R1, C1)),
+        )
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hstack() {
+        let a = Matrix::from([[1, 2], [4, 5]]);
+        let b = Matrix::from([[3], [6]]);
+        let m: Matrix<_, 2, 3> = Matrix::hstack(a, b);
+        assert_eq!(Matrix::from([[1, 2, 3], [4, 5, 6]]), m);
+    }
+    #[test]
+    fn vstack() {
+        let a = Matrix::from([[1, 2]]);
+        let b = Matrix::from([[3, 4], [5, 6]]);
+        let m: Matrix<_, 3, 2> = Matrix::vstack(a, b);
+        assert_eq!(Matrix::from([[1, 2], [3, 4], [5, 6]]), m);
+    }
+    #[test]
+    fn from_blocks() {
+        let a = Matrix::from([[1, 0], [0, 1]]);
+        let b = Matrix::from([[2], [3]]);
+        let c = Matrix::from([[2, 3]]);
+        let d = Matrix::from([[0]]);
+        let m: Matrix<_, 3, 3> = Matrix::from_blocks(a, b, c, d);
+        assert_eq!(Matrix::from([[1, 0, 2], [0, 1, 3], [2, 3, 0]]), m);
+    }
+    #[test]
+    fn split() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let (a, b): (Matrix<_, 3, 1>, Matrix<_, 3, 2>) = m.hsplit();
+        assert_eq!(Matrix::from([[1], [4], [7]]), a);
+        assert_eq!(Matrix::from([[2, 3], [5, 6], [8, 9]]), b);
+
+        let (top, bottom): (Matrix<_, 2, 3>, Matrix<_, 1, 3>) = m.vsplit();
+        assert_eq!(m, Matrix::vstack(top, bottom));
+
+        let (a, b, c, d) = m.into_blocks::<1, 2, 2, 1>();
+        assert_eq!(Matrix::from([[1, 2]]), a);
+        assert_eq!(Matrix::from([[3]]), b);
+        assert_eq!(Matrix::from([[4, 5], [7, 8]]), c);
+        assert_eq!(Matrix::from([[6], [9]]), d);
+    }
+    #[test]
+    fn empty_blocks() {
+        let m = Matrix::from([[1, 2], [3, 4]]);
+        let (a, b) = m.hsplit::<2, 0>();
+        assert_eq!(m, a);
+        assert_eq!(m, Matrix::hstack(a, b));
+
+        let (top, bottom) = m.vsplit::<0, 2>();
+        assert_eq!(m, bottom);
+        assert_eq!(m, Matrix::vstack(top, bottom));
+
+        let (a, b, c, d) = m.into_blocks::<2, 0, 0, 2>();
+        assert_eq!(m, b);
+        assert_eq!(m, Matrix::from_blocks(a, b, c, d));
+    }
+}
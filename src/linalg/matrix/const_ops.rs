@@ -0,0 +1,100 @@
+use super::Matrix;
+
+// Trait methods cannot be called in `const fn`, so the generic operations are
+// repeated here for every primitive element type.
+macro_rules! impl_const_ops {
+    ($($t:ty: $zero:literal, $one:literal);* $(;)?) => {
+        $(
+            impl<const R: usize, const C: usize> Matrix<$t, R, C> {
+                /// `transpose` usable in `const` items.
+                pub const fn transpose_const(self) -> Matrix<$t, C, R> {
+                    let mut m = [[$zero; R]; C];
+                    let mut row = 0;
+                    while row < R {
+                        let mut col = 0;
+                        while col < C {
+                            m[col][row] = self.0[row][col];
+                            col += 1;
+                        }
+                        row += 1;
+                    }
+                    Matrix::from(m)
+                }
+                /// `add` usable in `const` items.
+                pub const fn add_const(self, rhs: Self) -> Self {
+                    let mut m = [[$zero; C]; R];
+                    let mut row = 0;
+                    while row < R {
+                        let mut col = 0;
+                        while col < C {
+                            m[row][col] = self.0[row][col] + rhs.0[row][col];
+                            col += 1;
+                        }
+                        row += 1;
+                    }
+                    Matrix::from(m)
+                }
+                /// `mul` usable in `const` items, with the same summation order.
+                pub const fn mul_const<const K: usize>(self, rhs: Matrix<$t, C, K>) -> Matrix<$t, R, K> {
+                    let mut m = [[$zero; K]; R];
+                    let mut row = 0;
+                    while row < R {
+                        let mut col = 0;
+                        while col < K {
+                            let mut acc = $zero;
+                            let mut k = 0;
+                            while k < C {
+                                acc = acc + self.0[row][k] * rhs.0[k][col];
+                                k += 1;
+                            }
+                            m[row][col] = acc;
+                            col += 1;
+                        }
+                        row += 1;
+                    }
+                    Matrix::from(m)
+                }
+            }
+
+            impl<const N: usize> Matrix<$t, N, N> {
+                /// `identity` usable in `const` items.
+                pub const fn identity_const() -> Self {
+                    let mut m = [[$zero; N]; N];
+                    let mut i = 0;
+                    while i < N {
+                        m[i][i] = $one;
+                        i += 1;
+                    }
+                    Matrix::from(m)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_ops!(
+    i8: 0, 1; i16: 0, 1; i32: 0, 1; i64: 0, 1; i128: 0, 1; isize: 0, 1;
+    u8: 0, 1; u16: 0, 1; u32: 0, 1; u64: 0, 1; u128: 0, 1; usize: 0, 1;
+    f32: 0.0, 1.0; f64: 0.0, 1.0;
+);
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::vector::Vector;
+
+    const A: Matrix<f64, 2, 3> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    const AAT: Matrix<f64, 2, 2> = A.mul_const(A.transpose_const());
+    const I: Matrix<i32, 3, 3> = Matrix::<i32, 3, 3>::identity_const();
+    const V: Vector<u8, 2> = Vector::from([1, 2]);
+
+    #[test]
+    fn matches_runtime() {
+        assert_eq!(A * A.transpose(), AAT);
+        assert_eq!(A + A, A.add_const(A));
+        assert_eq!(Matrix::identity(), I);
+        assert_eq!(2, V[1]);
+    }
+}
@@ -0,0 +1,88 @@
+use super::Matrix;
+use crate::linalg::scalar::One;
+use crate::linalg::vector::Vector;
+
+impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn zeros() -> Self {
+        Matrix::new()
+    }
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(f: F) -> Self {
+        let mut f = f;
+        Matrix(core::array::from_fn(|row| core::array::from_fn(|col| f(row, col))))
+    }
+    pub fn from_rows(rows: [Vector<T, C>; R]) -> Self {
+        let mut m = Matrix::new();
+        for (row_num, row) in rows.into_iter().enumerate() {
+            for (col_num, val) in row.into_iter().enumerate() {
+                m[(row_num, col_num)] = val;
+            }
+        }
+        m
+    }
+    pub fn from_cols(cols: [Vector<T, R>; C]) -> Self {
+        let mut m = Matrix::new();
+        for (col_num, col) in cols.into_iter().enumerate() {
+            for (row_num, val) in col.into_iter().enumerate() {
+                m[(row_num, col_num)] = val;
+            }
+        }
+        m
+    }
+}
+
+impl<T: Default + One, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn ones() -> Self {
+        Matrix::from_fn(|_, _| T::one())
+    }
+}
+
+impl<T: Default, const N: usize> Matrix<T, N, N> {
+    pub fn from_diagonal(diagonal: Vector<T, N>) -> Self {
+        let mut m = Matrix::new();
+        for (i, val) in diagonal.into_iter().enumerate() {
+            m[(i, i)] = val;
+        }
+        m
+    }
+}
+
+impl<T: Default + One, const N: usize> Matrix<T, N, N> {
+    pub fn identity() -> Self {
+        Matrix::from_fn(|row, col| if row == col { T::one() } else { T::default() })
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let m: Matrix<i32, 3, 3> = Matrix::identity();
+        assert_eq!(Matrix::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]), m);
+    }
+    #[test]
+    fn zeros_ones() {
+        assert_eq!(Matrix::from([[0.0, 0.0]]), Matrix::zeros());
+        assert_eq!(Matrix::from([[1.0, 1.0]]), Matrix::ones());
+    }
+    #[test]
+    fn from_fn() {
+        let m = Matrix::from_fn(|row, col| 10 * row + col);
+        assert_eq!(Matrix::from([[0, 1], [10, 11], [20, 21]]), m);
+    }
+    #[test]
+    fn from_diagonal() {
+        let m = Matrix::from_diagonal(Vector::from([1, 2]));
+        assert_eq!(Matrix::from([[1, 0], [0, 2]]), m);
+    }
+    #[test]
+    fn from_rows_cols() {
+        let rows = [Vector::from([1, 2, 3]), Vector::from([4, 5, 6])];
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m, Matrix::from_rows(rows));
+        assert_eq!(m.transpose(), Matrix::from_cols(rows));
+    }
+}
@@ -0,0 +1,255 @@
+use super::exact::checked;
+use super::normal_form::gcd;
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::{Float, Integer};
+
+/// Reduced row echelon form together with its pivot columns.
+#[derive(Debug, PartialEq)]
+pub struct Echelon<T: Default, const R: usize, const C: usize> {
+    pub matrix: Matrix<T, R, C>,
+    pub rank: usize,
+    pivots: [usize; R],
+}
+
+impl<T: Default, const R: usize, const C: usize> Echelon<T, R, C> {
+    /// Pivot columns in increasing order, one per nonzero row.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots[..self.rank]
+    }
+    fn free_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..C).filter(|col| !self.pivots().contains(col))
+    }
+}
+
+impl<T: Default + Copy, const R: usize, const C: usize> Matrix<T, R, C> {
+    // the columns listed in `cols`, packed to the left
+    fn select_columns(&self, cols: &[usize]) -> (Self, usize) {
+        let mut m = Matrix::new();
+        for (j, &col) in cols.iter().enumerate() {
+            for row in 0..R {
+                m[(row, j)] = self[(row, col)];
+            }
+        }
+        (m, cols.len())
+    }
+}
+
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Reduced row echelon form by Gauss–Jordan elimination with partial
+    /// pivoting. Entries with magnitude at most `tol` are treated as zero.
+    pub fn rref(&self, tol: T) -> Echelon<T, R, C> {
+        let mut a = *self;
+        let mut pivots = [0; R];
+        let mut rank = 0;
+
+        for col in 0..C {
+            if rank == R {
+                break;
+            }
+            let mut pivot = rank;
+            for row in rank + 1..R {
+                if a[(row, col)].abs() > a[(pivot, col)].abs() {
+                    pivot = row;
+                }
+            }
+            if a[(pivot, col)].abs() <= tol {
+                for row in rank..R {
+                    a[(row, col)] = T::ZERO;
+                }
+                continue;
+            }
+            a.0.swap(rank, pivot);
+
+            let p = a[(rank, col)];
+            for j in col + 1..C {
+                a[(rank, j)] = a[(rank, j)] / p;
+            }
+            a[(rank, col)] = T::ONE;
+            for row in 0..R {
+                let factor = a[(row, col)];
+                if row == rank || factor == T::ZERO {
+                    continue;
+                }
+                for j in col + 1..C {
+                    a[(row, j)] = a[(row, j)] - factor * a[(rank, j)];
+                }
+                a[(row, col)] = T::ZERO;
+            }
+            pivots[rank] = col;
+            rank += 1;
+        }
+        Echelon { matrix: a, rank, pivots }
+    }
+
+    /// Numerical rank, see [`Matrix::rref`] for the meaning of `tol`.
+    pub fn rank(&self, tol: T) -> usize {
+        self.rref(tol).rank
+    }
+
+    /// Basis of the null space, returned as `(N, nullity)` where the first
+    /// `nullity` columns of `N` are the basis vectors and the rest are zero.
+    pub fn null_space(&self, tol: T) -> (Matrix<T, C, C>, usize) {
+        let e = self.rref(tol);
+        let mut n = Matrix::new();
+        for (j, free) in e.free_columns().enumerate() {
+            n[(free, j)] = T::ONE;
+            for (i, &pivot) in e.pivots().iter().enumerate() {
+                n[(pivot, j)] = -e.matrix[(i, free)];
+            }
+        }
+        (n, C - e.rank)
+    }
+
+    /// Basis of the column space, returned as `(B, rank)` where the first
+    /// `rank` columns of `B` are the pivot columns of `A`.
+    pub fn column_space(&self, tol: T) -> (Self, usize) {
+        self.select_columns(self.rref(tol).pivots())
+    }
+}
+
+// divides the slice by the gcd of its entries
+fn make_primitive<T: Integer>(v: &mut [T]) -> Result<(), LinalgError> {
+    let g = v.iter().try_fold(T::default(), |g, &x| gcd(g, x))?;
+    if g > T::one() {
+        v.iter_mut().for_each(|x| *x = *x / g);
+    }
+    Ok(())
+}
+
+impl<T: Integer, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Fraction-free reduced row echelon form.
+    ///
+    /// Instead of scaling pivots to one, every nonzero row is reduced to a
+    /// primitive integer vector with a positive pivot, and each pivot column
+    /// has no other nonzero entry. Overflow of `T` is reported as an error.
+    pub fn rref_exact(&self) -> Result<Echelon<T, R, C>, LinalgError> {
+        let zero = T::default();
+        let mut a = *self;
+        let mut pivots = [0; R];
+        let mut rank = 0;
+
+        for col in 0..C {
+            if rank == R {
+                break;
+            }
+            let Some(p) = (rank..R).find(|&row| a[(row, col)] != zero) else {
+                continue;
+            };
+            a.0.swap(p, rank);
+            if a[(rank, col)] < zero {
+                for x in a.0[rank].iter_mut() {
+                    *x = checked(zero.checked_sub(*x))?;
+                }
+            }
+            make_primitive(&mut a.0[rank])?;
+
+            let pivot = a[(rank, col)];
+            for row in 0..R {
+                let factor = a[(row, col)];
+                if row == rank || factor == zero {
+                    continue;
+                }
+                for j in 0..C {
+                    let v = checked(pivot.checked_mul(a[(row, j)]))?;
+                    let w = checked(factor.checked_mul(a[(rank, j)]))?;
+                    a[(row, j)] = checked(v.checked_sub(w))?;
+                }
+                make_primitive(&mut a.0[row])?;
+            }
+            pivots[rank] = col;
+            rank += 1;
+        }
+        Ok(Echelon { matrix: a, rank, pivots })
+    }
+
+    /// Integer basis of the null space with primitive basis vectors, returned
+    /// as `(N, nullity)` like [`Matrix::null_space`].
+    pub fn null_space_exact(&self) -> Result<(Matrix<T, C, C>, usize), LinalgError> {
+        let e = self.rref_exact()?;
+        let mut n = Matrix::new();
+        for (j, free) in e.free_columns().enumerate() {
+            // common multiple of the pivots involved keeps the vector integral
+            let mut scale = T::one();
+            for (i, &pivot) in e.pivots().iter().enumerate() {
+                if e.matrix[(i, free)] != T::default() {
+                    let p = e.matrix[(i, pivot)];
+                    scale = checked((scale / gcd(scale, p)?).checked_mul(p))?;
+                }
+            }
+            let mut x = [T::default(); C];
+            x[free] = scale;
+            for (i, &pivot) in e.pivots().iter().enumerate() {
+                let factor = scale / e.matrix[(i, pivot)];
+                let v = checked(factor.checked_mul(e.matrix[(i, free)]))?;
+                x[pivot] = checked(T::default().checked_sub(v))?;
+            }
+            make_primitive(&mut x)?;
+            for (row, val) in x.into_iter().enumerate() {
+                n[(row, j)] = val;
+            }
+        }
+        Ok((n, C - e.rank))
+    }
+
+    /// Basis of the column space, returned as `(B, rank)` where the first
+    /// `rank` columns of `B` are the pivot columns of `A`.
+    pub fn column_space_exact(&self) -> Result<(Self, usize), LinalgError> {
+        Ok(self.select_columns(self.rref_exact()?.pivots()))
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn system() -> Matrix<f64, 3, 4> {
+        Matrix::from([[1.0, 2.0, 0.0, 3.0], [2.0, 4.0, 1.0, 7.0], [1.0, 2.0, 1.0, 4.0]])
+    }
+
+    #[test]
+    fn rref() {
+        let e = system().rref(1e-12);
+        let expected = Matrix::from([[1.0, 2.0, 0.0, 3.0], [0.0, 0.0, 1.0, 1.0], [0.0; 4]]);
+        assert!((e.matrix - expected).frobenius_norm() < 1e-12);
+        assert_eq!(&[0, 2], e.pivots());
+        assert_eq!(2, system().rank(1e-12));
+        assert_eq!(0, Matrix::<f64, 2, 2>::new().rank(1e-12));
+    }
+    #[test]
+    fn null_and_column_space() {
+        let a = system();
+        let (n, nullity) = a.null_space(1e-12);
+        assert_eq!(2, nullity);
+        assert!((a * n).frobenius_norm() < 1e-12);
+        assert_eq!(-2.0, n[(0, 0)]);
+        assert_eq!(1.0, n[(1, 0)]);
+
+        let (b, rank) = a.column_space(1e-12);
+        assert_eq!(2, rank);
+        assert_eq!([1.0, 0.0, 0.0, 0.0], b.0[0]);
+        assert_eq!([1.0, 1.0, 0.0, 0.0], b.0[2]);
+    }
+    #[test]
+    fn rref_exact() {
+        let a = Matrix::from([[2i64, 3, 1], [4, 6, 5], [6, 9, 0]]);
+        let e = a.rref_exact().unwrap();
+        assert_eq!(Matrix::from([[2, 3, 0], [0, 0, 1], [0, 0, 0]]), e.matrix);
+        assert_eq!(&[0, 2], e.pivots());
+        assert_eq!(Ok(2), a.column_space_exact().map(|(_, rank)| rank));
+
+        let (n, nullity) = a.null_space_exact().unwrap();
+        assert_eq!(1, nullity);
+        assert_eq!(Matrix::from([[-3, 0, 0], [2, 0, 0], [0, 0, 0]]), n);
+    }
+    #[test]
+    fn null_space_exact_mixed_pivots() {
+        let a = Matrix::from([[2i64, 0, 1], [0, 3, 1]]);
+        let (n, nullity) = a.null_space_exact().unwrap();
+        assert_eq!(1, nullity);
+        assert_eq!(Matrix::from([[-3, 0, 0], [-2, 0, 0], [6, 0, 0]]), n);
+        assert_eq!(Matrix::<i64, 2, 3>::new(), a * n);
+    }
+}
@@ -0,0 +1,187 @@
+use super::special;
+use super::Matrix;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+
+/// Householder reflector `H = I - β v vᵀ`, applied without forming `H`.
+#[derive(Debug, PartialEq)]
+pub struct Householder<T, const L: usize> {
+    v: Vector<T, L>,
+    beta: T,
+    start: usize,
+}
+
+impl<T: Float, const L: usize> Householder<T, L> {
+    /// Reflector with `Hx = αe₀`, where `|α| = ‖x‖`.
+    pub fn from_vector(x: &Vector<T, L>) -> Self {
+        Self::from_tail(x, 0)
+    }
+
+    /// Reflector acting on the components `start..` only, mapping `x` to a
+    /// vector that is zero after `start`.
+    ///
+    /// The sign of `α` is opposite to `x[start]` to avoid cancellation. If
+    /// there is nothing to annihilate, the result is the identity.
+    pub fn from_tail(x: &Vector<T, L>, start: usize) -> Self {
+        let mut v = Vector::from_value(T::ZERO);
+        let tail = (start + 1..L).fold(T::ZERO, |acc, i| acc + x[i] * x[i]);
+        if tail == T::ZERO {
+            return Householder { v, beta: T::ZERO, start };
+        }
+        let norm = T::sqrt(x[start] * x[start] + tail);
+        let alpha = if x[start] < T::ZERO { norm } else { -norm };
+        v[start] = x[start] - alpha;
+        for i in start + 1..L {
+            v[i] = x[i];
+        }
+        let beta = T::ONE / (norm * (norm + x[start].abs()));
+        Householder { v, beta, start }
+    }
+
+    /// `M = HM`.
+    pub fn apply_left<const C: usize>(&self, m: &mut Matrix<T, L, C>) {
+        for col in 0..C {
+            let dot = (self.start..L).fold(T::ZERO, |acc, i| acc + self.v[i] * m[(i, col)]);
+            let w = self.beta * dot;
+            for i in self.start..L {
+                m[(i, col)] = m[(i, col)] - self.v[i] * w;
+            }
+        }
+    }
+
+    /// `M = MH`.
+    pub fn apply_right<const R: usize>(&self, m: &mut Matrix<T, R, L>) {
+        for row in 0..R {
+            let dot = (self.start..L).fold(T::ZERO, |acc, j| acc + m[(row, j)] * self.v[j]);
+            let w = self.beta * dot;
+            for j in self.start..L {
+                m[(row, j)] = m[(row, j)] - w * self.v[j];
+            }
+        }
+    }
+
+    /// `x = Hx`.
+    pub fn apply_vector(&self, x: &mut Vector<T, L>) {
+        let dot = (self.start..L).fold(T::ZERO, |acc, i| acc + self.v[i] * x[i]);
+        let w = self.beta * dot;
+        for i in self.start..L {
+            x[i] = x[i] - self.v[i] * w;
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T, L, L> {
+        let mut m = Matrix::identity();
+        self.apply_left(&mut m);
+        m
+    }
+}
+
+/// Givens rotation `[c s; -s c]` in a coordinate plane `(i, k)`, see
+/// [`special::givens`] for the dense form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<T> {
+    pub c: T,
+    pub s: T,
+}
+
+impl<T: Float> Givens<T> {
+    /// Rotation mapping `(a, b)` to `(r, 0)` with `r = √(a² + b²)`.
+    pub fn new(a: T, b: T) -> Self {
+        if b == T::ZERO {
+            let c = if a < T::ZERO { -T::ONE } else { T::ONE };
+            return Givens { c, s: T::ZERO };
+        }
+        // scale by the larger magnitude so the squares cannot overflow
+        let scale = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+        let (x, y) = (a / scale, b / scale);
+        let r = scale * T::sqrt(x * x + y * y);
+        Givens { c: a / r, s: b / r }
+    }
+
+    /// Rotates the pair `(a, b)`.
+    pub fn apply(&self, a: T, b: T) -> (T, T) {
+        (self.c * a + self.s * b, self.c * b - self.s * a)
+    }
+
+    /// Rotates rows `i` and `k`, `M = GM`.
+    pub fn apply_left<const R: usize, const C: usize>(&self, m: &mut Matrix<T, R, C>, i: usize, k: usize) {
+        for col in 0..C {
+            (m[(i, col)], m[(k, col)]) = self.apply(m[(i, col)], m[(k, col)]);
+        }
+    }
+
+    /// Rotates columns `i` and `k`, `M = MG`.
+    pub fn apply_right<const R: usize, const C: usize>(&self, m: &mut Matrix<T, R, C>, i: usize, k: usize) {
+        let transposed = Givens { c: self.c, s: -self.s };
+        for row in 0..R {
+            (m[(row, i)], m[(row, k)]) = transposed.apply(m[(row, i)], m[(row, k)]);
+        }
+    }
+
+    pub fn to_matrix<const N: usize>(&self, i: usize, k: usize) -> Matrix<T, N, N> {
+        special::givens(i, k, self.c, self.s)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Matrix<f64, 3, 3> {
+        Matrix::from([[3.0, 1.0, 2.0], [4.0, -1.0, 0.5], [0.0, 2.0, 1.0]])
+    }
+
+    #[test]
+    fn householder() {
+        let x = Vector::from([3.0, 4.0, 0.0]);
+        let h = Householder::from_vector(&x);
+        let mut y = x;
+        h.apply_vector(&mut y);
+        assert!(y.dist(&Vector::from([-5.0, 0.0, 0.0])) < 1e-12);
+
+        let dense = h.to_matrix();
+        assert!((dense * dense.transpose() - Matrix::identity()).frobenius_norm() < 1e-12);
+        let mut m = sample();
+        h.apply_left(&mut m);
+        assert!((m - dense * sample()).frobenius_norm() < 1e-12);
+        let mut m = sample();
+        h.apply_right(&mut m);
+        assert!((m - sample() * dense).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn householder_tail() {
+        let x = Vector::from([7.0, -1.0, 0.0, 1.0]);
+        let h = Householder::from_tail(&x, 1);
+        let mut y = x;
+        h.apply_vector(&mut y);
+        assert!(y.dist(&Vector::from([7.0, 2f64.sqrt(), 0.0, 0.0])) < 1e-12);
+        assert_eq!(1.0, h.to_matrix()[(0, 0)]);
+
+        let e = Vector::from([1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(Matrix::identity(), Householder::from_vector(&e).to_matrix());
+    }
+    #[test]
+    fn givens() {
+        let g = Givens::new(3.0, 4.0);
+        let (r, zero) = g.apply(3.0, 4.0);
+        assert!((r - 5.0).abs() < 1e-12 && zero.abs() < 1e-12);
+
+        let dense = g.to_matrix(0, 1);
+        let mut m = sample();
+        g.apply_left(&mut m, 0, 1);
+        assert!((m - dense * sample()).frobenius_norm() < 1e-12);
+        assert!(m[(1, 0)].abs() < 1e-12);
+        let mut m = sample();
+        g.apply_right(&mut m, 0, 1);
+        assert!((m - sample() * dense).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn givens_zero_b() {
+        let g = Givens::new(-3.0, 0.0);
+        assert_eq!(Givens { c: -1.0, s: 0.0 }, g);
+        assert_eq!((3.0, 0.0), g.apply(-3.0, 0.0));
+        assert_eq!(Givens { c: 1.0, s: 0.0 }, Givens::new(0.0, 0.0));
+    }
+}
@@ -0,0 +1,123 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Integer;
+
+pub(super) fn checked<T>(v: Option<T>) -> Result<T, LinalgError> {
+    v.ok_or(LinalgError::Overflow)
+}
+
+// One step of Bareiss' fraction-free elimination below the pivot `(k, col)`,
+// `a[i][j] = (a[k][col] a[i][j] - a[i][col] a[k][j]) / prev`. The division is
+// exact since every entry is a minor of the original matrix. Takes the rows
+// as a slice, so that matrices with a computed number of rows can use it too.
+pub(super) fn bareiss_step<T: Integer, const C: usize>(
+    a: &mut [[T; C]],
+    (k, col): (usize, usize),
+    prev: T,
+) -> Result<(), LinalgError> {
+    let pivot_row = a[k];
+    let pivot = pivot_row[col];
+    for row in a[k + 1..].iter_mut() {
+        let factor = row[col];
+        for (x, &y) in row[col + 1..].iter_mut().zip(&pivot_row[col + 1..]) {
+            let v = checked(pivot.checked_mul(*x))?;
+            let w = checked(factor.checked_mul(y))?;
+            *x = checked(checked(v.checked_sub(w))?.checked_div(prev))?;
+        }
+        row[col] = T::default();
+    }
+    Ok(())
+}
+
+impl<T: Integer, const N: usize> Matrix<T, N, N> {
+    /// Exact determinant by Bareiss' fraction-free elimination.
+    ///
+    /// All intermediate values are minors of `A`, so they stay as small as
+    /// the determinant allows. Overflow of `T` is reported as an error.
+    pub fn determinant_exact(&self) -> Result<T, LinalgError> {
+        let zero = T::default();
+        let mut a = *self;
+        let mut negative = false;
+        let mut prev = T::one();
+
+        for k in 0..N {
+            let Some(p) = (k..N).find(|&row| a[(row, k)] != zero) else {
+                return Ok(zero);
+            };
+            if p != k {
+                a.0.swap(p, k);
+                negative = !negative;
+            }
+            bareiss_step(&mut a.0, (k, k), prev)?;
+            prev = a[(k, k)];
+        }
+
+        if negative {
+            checked(zero.checked_sub(prev))
+        } else {
+            Ok(prev)
+        }
+    }
+}
+
+impl<T: Integer, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Exact rank by fraction-free elimination. Overflow of `T` is reported
+    /// as an error.
+    pub fn rank_exact(&self) -> Result<usize, LinalgError> {
+        let zero = T::default();
+        let mut a = *self;
+        let mut prev = T::one();
+        let mut rank = 0;
+
+        for col in 0..C {
+            if rank == R {
+                break;
+            }
+            let Some(p) = (rank..R).find(|&row| a[(row, col)] != zero) else {
+                continue;
+            };
+            a.0.swap(p, rank);
+            bareiss_step(&mut a.0, (rank, col), prev)?;
+            prev = a[(rank, col)];
+            rank += 1;
+        }
+        Ok(rank)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn determinant_exact() {
+        // spanning trees of K4, a reduced Laplacian
+        let l = Matrix::from([[3i64, -1, -1], [-1, 3, -1], [-1, -1, 3]]);
+        assert_eq!(Ok(16), l.determinant_exact());
+        assert_eq!(Ok(-1), Matrix::from([[0i32, 1], [1, 0]]).determinant_exact());
+        assert_eq!(Ok(0), Matrix::from([[1i32, 2], [2, 4]]).determinant_exact());
+        assert_eq!(Ok(1), Matrix::<i32, 0, 0>::new().determinant_exact());
+    }
+    #[test]
+    fn determinant_exact_large() {
+        // Vandermonde matrix of 1..=6
+        let m: Matrix<i128, 6, 6> = Matrix::from_fn(|i, j| ((i + 1) as i128).pow(j as u32));
+        // prod_(i<j) (x_j - x_i) = 1! 2! 3! 4! 5!
+        let expected = (1..6).fold(1i128, |acc, k| acc * (1..=k).product::<i128>());
+        assert_eq!(Ok(expected), m.determinant_exact());
+    }
+    #[test]
+    fn overflow() {
+        let m = Matrix::from([[100i8, 1], [1, 100]]);
+        assert_eq!(Err(LinalgError::Overflow), m.determinant_exact());
+    }
+    #[test]
+    fn rank_exact() {
+        let m = Matrix::from([[1i32, 2, 3], [2, 4, 6], [1, 0, 1]]);
+        assert_eq!(Ok(2), m.rank_exact());
+        assert_eq!(Ok(0), Matrix::<i32, 2, 3>::new().rank_exact());
+        assert_eq!(Ok(2), Matrix::from([[0i64, 1, 2, 3], [0, 2, 4, 7]]).rank_exact());
+    }
+}
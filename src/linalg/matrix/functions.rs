@@ -0,0 +1,212 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+
+const PADE_DEGREE: usize = 6;
+const MAX_ITERATIONS: usize = 100;
+const MAX_SQUARE_ROOTS: usize = 64;
+const MAX_SERIES_TERMS: usize = 100;
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    fn norm_one(&self) -> T {
+        (0..N)
+            .map(|col| (0..N).fold(T::ZERO, |acc, row| acc + self[(row, col)].abs()))
+            .fold(T::ZERO, |acc, v| if v > acc { v } else { acc })
+    }
+
+    fn check_finite(&self) -> Result<(), LinalgError> {
+        if self.iter().all(|v| v.is_finite()) {
+            Ok(())
+        } else {
+            Err(LinalgError::NonFinite)
+        }
+    }
+
+    /// Matrix exponential by scaling and squaring with a degree 6 Padé
+    /// approximant.
+    ///
+    /// `A` is scaled by `2^-s` until `|A|_1 <= 1/2`, where the approximant
+    /// is accurate to working precision, and the result is squared `s`
+    /// times. Fails with `LinalgError::NonFinite` when `A` has NaN or
+    /// infinite entries, or when its norm or the result overflows.
+    pub fn exp(&self) -> Result<Self, LinalgError> {
+        self.check_finite()?;
+        let half = T::ONE / (T::ONE + T::ONE);
+        let mut norm = self.norm_one();
+        if !norm.is_finite() {
+            return Err(LinalgError::NonFinite);
+        }
+        let mut squarings = 0;
+        let mut scale = T::ONE;
+        while norm > half && squarings < 1024 {
+            norm = norm * half;
+            scale = scale * half;
+            squarings += 1;
+        }
+        let x = *self * scale;
+
+        // c_k = c_(k-1) (q - k + 1) / (k (2q - k + 1))
+        let q = PADE_DEGREE;
+        let mut c = T::ONE;
+        let mut power = Matrix::identity();
+        let mut numerator = Matrix::identity();
+        let mut denominator = Matrix::identity();
+        for k in 1..=q {
+            c = c * T::from_usize(q - k + 1) / T::from_usize(k * (2 * q - k + 1));
+            power = power * x;
+            numerator = numerator + power * c;
+            denominator = if k % 2 == 0 { denominator + power * c } else { denominator - power * c };
+        }
+
+        let mut e = denominator.inverse()? * numerator;
+        for _ in 0..squarings {
+            e = e * e;
+        }
+        e.check_finite()?;
+        Ok(e)
+    }
+
+    /// Principal square root by the Denman–Beavers iteration
+    /// `Y = (Y + Z^-1) / 2`, `Z = (Z + Y^-1) / 2`, starting from `Y = A`,
+    /// `Z = I`.
+    ///
+    /// It exists when `A` has no eigenvalues on the closed negative real
+    /// axis. Otherwise the iteration hits a singular iterate or fails to
+    /// converge, both of which are reported, as are non-finite entries.
+    pub fn sqrt(&self) -> Result<Self, LinalgError> {
+        self.check_finite()?;
+        let half = T::ONE / (T::ONE + T::ONE);
+        let tol = T::EPSILON * T::from_usize(N);
+        let mut y = *self;
+        let mut z = Matrix::identity();
+        let mut converging = false;
+
+        for _ in 0..MAX_ITERATIONS {
+            let y_inv = y.inverse()?;
+            let z_inv = z.inverse()?;
+            let next = (y + z_inv) * half;
+            z = (z + y_inv) * half;
+            let diff = (next - y).frobenius_norm();
+            y = next;
+
+            // quadratic convergence, one more step after the step size drops
+            // below the square root of the tolerance
+            if diff <= T::sqrt(tol) * y.frobenius_norm() {
+                if converging {
+                    return Ok(y);
+                }
+                converging = true;
+            }
+        }
+        Err(LinalgError::NoConvergence { iterations: MAX_ITERATIONS })
+    }
+
+    /// Principal logarithm by inverse scaling and squaring.
+    ///
+    /// Square roots are taken until `|A^(1/2^k) - I|_1 <= 1/4`, then
+    /// `log(I + X) = 2 atanh(X (2I + X)^-1)` is summed as a series and the
+    /// result is multiplied by `2^k`. It exists when `A` has no eigenvalues on
+    /// the closed negative real axis, failures of the square roots or of the
+    /// series are reported, as are non-finite entries.
+    pub fn ln(&self) -> Result<Self, LinalgError> {
+        self.check_finite()?;
+        let quarter = T::ONE / T::from_usize(4);
+        let identity = Matrix::identity();
+        let mut a = *self;
+        let mut roots = 0;
+        while (a - identity).norm_one() > quarter {
+            if roots == MAX_SQUARE_ROOTS {
+                return Err(LinalgError::NoConvergence { iterations: roots });
+            }
+            a = a.sqrt()?;
+            roots += 1;
+        }
+
+        let x = a - identity;
+        let z = x * (identity * (T::ONE + T::ONE) + x).inverse()?;
+        let z2 = z * z;
+        let mut power = z;
+        let mut sum = z;
+        let mut converged = false;
+        for k in 1..MAX_SERIES_TERMS {
+            power = power * z2;
+            let term = power * (T::ONE / T::from_usize(2 * k + 1));
+            sum = sum + term;
+            if term.frobenius_norm() <= T::EPSILON * sum.frobenius_norm() {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(LinalgError::NoConvergence { iterations: MAX_SERIES_TERMS });
+        }
+
+        // 2 from the atanh series, 2^k from the square roots
+        let scale = (0..roots).fold(T::ONE + T::ONE, |acc, _| acc + acc);
+        Ok(sum * scale)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close<const N: usize>(a: Matrix<f64, N, N>, b: Matrix<f64, N, N>) {
+        assert!((a - b).frobenius_norm() <= 1e-12 * b.frobenius_norm().max(1.0), "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn exp() {
+        assert_close(Matrix::<f64, 3, 3>::zeros().exp().unwrap(), Matrix::identity());
+        let d = Matrix::from([[1.0, 0.0], [0.0, 2.0]]).exp().unwrap();
+        assert_close(d, Matrix::from([[1f64.exp(), 0.0], [0.0, 2f64.exp()]]));
+        let nilpotent = Matrix::from([[0.0, 1.0], [0.0, 0.0]]).exp().unwrap();
+        assert_close(nilpotent, Matrix::from([[1.0, 1.0], [0.0, 1.0]]));
+    }
+    #[test]
+    fn exp_rotation() {
+        let theta = 2.5;
+        let r = Matrix::from([[0.0, -theta], [theta, 0.0]]).exp().unwrap();
+        assert_close(r, Matrix::from([[theta.cos(), -theta.sin()], [theta.sin(), theta.cos()]]));
+    }
+    #[test]
+    fn sqrt() {
+        let s = Matrix::from([[4.0, 0.0], [0.0, 9.0]]).sqrt().unwrap();
+        assert_close(s, Matrix::from([[2.0, 0.0], [0.0, 3.0]]));
+        let a = Matrix::from([[5.0, 2.0, 0.0], [2.0, 6.0, 1.0], [0.0, 1.0, 7.0]]);
+        let s = a.sqrt().unwrap();
+        assert_close(s * s, a);
+    }
+    #[test]
+    fn ln() {
+        assert_close(Matrix::<f64, 2, 2>::identity().ln().unwrap(), Matrix::zeros());
+        let a = Matrix::from([[0.5, 1.0, 0.0], [-1.0, 0.2, 0.3], [0.0, 0.1, -0.4]]);
+        assert_close(a.exp().unwrap().ln().unwrap(), a);
+        let big = Matrix::from([[100.0, 0.0], [0.0, 0.01]]);
+        assert_close(big.ln().unwrap(), Matrix::from([[100f64.ln(), 0.0], [0.0, 0.01f64.ln()]]));
+    }
+    #[test]
+    fn negative_eigenvalues() {
+        let a = Matrix::from([[-1.0, 0.0], [0.0, -2.0]]);
+        assert!(a.sqrt().is_err());
+        assert!(a.ln().is_err());
+    }
+    #[test]
+    fn non_finite() {
+        for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let a = Matrix::from([[1.0, v], [0.0, 1.0]]);
+            assert_eq!(Err(LinalgError::NonFinite), a.exp());
+            assert_eq!(Err(LinalgError::NonFinite), a.sqrt());
+            assert_eq!(Err(LinalgError::NonFinite), a.ln());
+        }
+    }
+    #[test]
+    fn exp_overflow() {
+        let huge = Matrix::from([[1e308, 1e308], [1e308, 1e308]]);
+        assert_eq!(Err(LinalgError::NonFinite), huge.exp());
+        let large = Matrix::from([[800.0, 0.0], [0.0, 0.0]]);
+        assert_eq!(Err(LinalgError::NonFinite), large.exp());
+    }
+}
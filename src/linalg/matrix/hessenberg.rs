@@ -0,0 +1,87 @@
+use super::elementary::Householder;
+use super::Matrix;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Reduction to upper Hessenberg form by Householder similarity
+    /// transformations, returns `(Q, H)` with `A = QHQᵀ` and `Q` orthogonal.
+    pub fn hessenberg(&self) -> (Self, Self) {
+        let mut h = *self;
+        let mut q = Matrix::identity();
+        for k in 0..N.saturating_sub(2) {
+            let x = Vector::from_fn(|i| h[(i, k)]);
+            let p = Householder::from_tail(&x, k + 1);
+            p.apply_left(&mut h);
+            p.apply_right(&mut h);
+            p.apply_right(&mut q);
+            for i in k + 2..N {
+                h[(i, k)] = T::ZERO;
+            }
+        }
+        (q, h)
+    }
+
+    /// Reduction of a symmetric matrix to tridiagonal form, returns
+    /// `(Q, d, e)` with `A = QTQᵀ`, where `T` has diagonal `d` and
+    /// off-diagonal `e[..N - 1]`. The last entry of `e` is zero.
+    ///
+    /// Only the symmetry of `A` is assumed, it is not checked.
+    pub fn symmetric_tridiagonal(&self) -> (Self, Vector<T, N>, Vector<T, N>) {
+        let (q, h) = self.hessenberg();
+        let d = Vector::from_fn(|i| h[(i, i)]);
+        let e = Vector::from_fn(|i| if i + 1 < N { h[(i + 1, i)] } else { T::ZERO });
+        (q, d, e)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_orthogonal<const N: usize>(q: &Matrix<f64, N, N>) -> bool {
+        (q.transpose() * *q - Matrix::identity()).frobenius_norm() < 1e-12
+    }
+
+    #[test]
+    fn hessenberg() {
+        let a = Matrix::from([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 3.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let (q, h) = a.hessenberg();
+        assert!(is_orthogonal(&q));
+        assert!((q * h * q.transpose() - a).frobenius_norm() < 1e-12);
+        for i in 2..4 {
+            for j in 0..i - 1 {
+                assert_eq!(0.0, h[(i, j)]);
+            }
+        }
+    }
+    #[test]
+    fn symmetric_tridiagonal() {
+        let a = Matrix::from([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let (q, d, e) = a.symmetric_tridiagonal();
+        assert!(is_orthogonal(&q));
+        assert_eq!(0.0, e[3]);
+        let t = Matrix::from_fn(|i, j| {
+            if i == j {
+                d[i]
+            } else if i == j + 1 || j == i + 1 {
+                e[i.min(j)]
+            } else {
+                0.0
+            }
+        });
+        assert!((q * t * q.transpose() - a).frobenius_norm() < 1e-12);
+    }
+}
@@ -1,5 +1,5 @@
 use super::Matrix;
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 
 impl<T: Default, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
     type Output = T;
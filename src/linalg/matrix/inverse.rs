@@ -0,0 +1,67 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Inverse by Gauss–Jordan elimination with partial pivoting.
+    pub fn inverse(&self) -> Result<Self, LinalgError> {
+        let mut a = *self;
+        let mut inv = Matrix::identity();
+
+        let scale = a.iter().fold(T::ZERO, |acc, &v| if v.abs() > acc { v.abs() } else { acc });
+        let tol = T::EPSILON * T::from_usize(N) * scale;
+
+        for col in 0..N {
+            let mut pivot = col;
+            for row in col + 1..N {
+                if a[(row, col)].abs() > a[(pivot, col)].abs() {
+                    pivot = row;
+                }
+            }
+            if a[(pivot, col)].abs() <= tol || a[(pivot, col)] == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            a.0.swap(col, pivot);
+            inv.0.swap(col, pivot);
+
+            let p = a[(col, col)];
+            for j in 0..N {
+                a[(col, j)] = a[(col, j)] / p;
+                inv[(col, j)] = inv[(col, j)] / p;
+            }
+            for row in 0..N {
+                if row == col {
+                    continue;
+                }
+                let factor = a[(row, col)];
+                if factor == T::ZERO {
+                    continue;
+                }
+                for j in 0..N {
+                    a[(row, j)] = a[(row, j)] - factor * a[(col, j)];
+                    inv[(row, j)] = inv[(row, j)] - factor * inv[(col, j)];
+                }
+            }
+        }
+        Ok(inv)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse() {
+        let m = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+        let inv = Matrix::from([[0.6, -0.7], [-0.2, 0.4]]);
+        assert!((m.inverse().unwrap() - inv).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn singular() {
+        let m = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(Err(LinalgError::Singular), m.inverse());
+    }
+}
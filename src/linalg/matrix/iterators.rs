@@ -1,6 +1,6 @@
 
 use super::Matrix;
-use std::iter::Iterator;
+use core::iter::Iterator;
 pub struct MatrixIterator<T: Default, const R: usize, const C: usize> {
     matrix: Matrix<T, R, C>,
     iter_index: (usize, usize),
@@ -30,9 +30,9 @@ impl<T: Default, const R: usize, const C: usize> Iterator for MatrixIterator<T,
             return None;
         }
 
-        let elem = std::mem::replace(self.matrix.get_mut((cur_row, cur_col)), T::default()); 
+        let elem = core::mem::take(self.matrix.get_mut((cur_row, cur_col))); 
         let (cur_row, cur_col) = if cur_col + 1 == col_total {
-            (cur_row + 1, 0 as usize)
+            (cur_row + 1, 0_usize)
         } else {
             (cur_row, cur_col + 1)
         };
@@ -52,15 +52,11 @@ impl<T: Default, const R: usize, const C: usize> Iterator for MatrixEnumerator<T
 
     fn next(&mut self) -> Option<Self::Item> {
         let index = self.0.iter_index;
-        if let Some(v) = self.0.next() {
-            Some((index, v))
-        } else {
-            None
-        }
+        self.0.next().map(|v| (index, v))
     }
 }
 
-use std::iter::IntoIterator;
+use core::iter::IntoIterator;
 impl<T: Default, const R: usize, const C: usize> IntoIterator for Matrix<T, R, C> {
     type Item = T;
     type IntoIter = MatrixIterator<T, R, C>;
@@ -69,7 +65,7 @@ impl<T: Default, const R: usize, const C: usize> IntoIterator for Matrix<T, R, C
         MatrixIterator::new(self)
     }
 }
-use std::iter::FromIterator;
+use core::iter::FromIterator;
 impl<T: Default, const R: usize, const C: usize> FromIterator<T> for Matrix<T, R, C> {
     fn from_iter<I: IntoIterator<Item=T>>(iter: I) -> Self {
         let mut m: Matrix<T, R, C> = Matrix::new();
@@ -114,7 +110,7 @@ impl<'a, T: Default, const R: usize, const C: usize> Iterator for MatrixIter<'a,
 
         let elem = &self.matrix[(cur_row, cur_col)];
         let (cur_row, cur_col) = if cur_col + 1 == col_total {
-            (cur_row + 1, 0 as usize)
+            (cur_row + 1, 0_usize)
         } else {
             (cur_row, cur_col + 1)
         };
@@ -135,15 +131,11 @@ impl<'a, T: Default, const R: usize, const C: usize> Iterator for MatrixEnum<'a,
 
     fn next(&mut self) -> Option<Self::Item> {
         let index = self.0.iter_index;
-        if let Some(v) = self.0.next() {
-            Some((index, v))
-        } else {
-            None
-        }
+        self.0.next().map(|v| (index, v))
     }
 }
 
-use std::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut};
 pub struct MutWrapper<T> {
     pointer: *mut T,
 }
@@ -196,7 +188,7 @@ impl<'a, T: Default, const R: usize, const C: usize> Iterator for MatrixIterMut<
 
         let elem = MutWrapper::new( &mut self.matrix[(cur_row, cur_col)] as *mut T );
         let (cur_row, cur_col) = if cur_col + 1 == col_total {
-            (cur_row + 1, 0 as usize)
+            (cur_row + 1, 0_usize)
         } else {
             (cur_row, cur_col + 1)
         };
@@ -217,11 +209,7 @@ impl<'a, T: Default, const R: usize, const C: usize> Iterator for MatrixEnumMut<
 
     fn next(&mut self) -> Option<Self::Item> {
         let index = self.0.iter_index;
-        if let Some(v) = self.0.next() {
-            Some((index, v))
-        } else {
-            None
-        }
+        self.0.next().map(|v| (index, v))
     }
 }
 
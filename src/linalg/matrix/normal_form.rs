@@ -0,0 +1,263 @@
+use super::exact::checked;
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Integer;
+
+// `(g, x, y)` with `x a + y b = g = ±gcd(a, b)`
+pub(super) fn extended_gcd<T: Integer>(a: T, b: T) -> Result<(T, T, T), LinalgError> {
+    let zero = T::default();
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::one(), zero);
+    let (mut old_t, mut t) = (zero, T::one());
+    while r != zero {
+        let q = checked(old_r.checked_div(r))?;
+        (old_r, r) = (r, checked(old_r.checked_sub(checked(q.checked_mul(r))?))?);
+        (old_s, s) = (s, checked(old_s.checked_sub(checked(q.checked_mul(s))?))?);
+        (old_t, t) = (t, checked(old_t.checked_sub(checked(q.checked_mul(t))?))?);
+    }
+    Ok((old_r, old_s, old_t))
+}
+
+// rows `(i, k)` become `(a i + b k, c i + d k)`
+fn combine_rows<T: Integer, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, (i, k): (usize, usize), [a, b, c, d]: [T; 4]) -> Result<(), LinalgError> {
+    for col in 0..C {
+        let (x, y) = (m[(i, col)], m[(k, col)]);
+        m[(i, col)] = checked(checked(a.checked_mul(x))?.checked_add(checked(b.checked_mul(y))?))?;
+        m[(k, col)] = checked(checked(c.checked_mul(x))?.checked_add(checked(d.checked_mul(y))?))?;
+    }
+    Ok(())
+}
+
+// columns `(j, l)` become `(a j + b l, c j + d l)`
+fn combine_cols<T: Integer, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, (j, l): (usize, usize), [a, b, c, d]: [T; 4]) -> Result<(), LinalgError> {
+    for row in 0..R {
+        let (x, y) = (m[(row, j)], m[(row, l)]);
+        m[(row, j)] = checked(checked(a.checked_mul(x))?.checked_add(checked(b.checked_mul(y))?))?;
+        m[(row, l)] = checked(checked(c.checked_mul(x))?.checked_add(checked(d.checked_mul(y))?))?;
+    }
+    Ok(())
+}
+
+fn neg<T: Integer>(v: T) -> Result<T, LinalgError> {
+    checked(T::default().checked_sub(v))
+}
+
+// non-negative gcd, `extended_gcd` without the Bézout coefficients
+pub(super) fn gcd<T: Integer>(a: T, b: T) -> Result<T, LinalgError> {
+    let (g, _, _) = extended_gcd(a, b)?;
+    if g < T::default() {
+        neg(g)
+    } else {
+        Ok(g)
+    }
+}
+
+fn negate_row<T: Integer, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, i: usize) -> Result<(), LinalgError> {
+    for col in 0..C {
+        m[(i, col)] = neg(m[(i, col)])?;
+    }
+    Ok(())
+}
+
+fn swap_cols<T: Integer, const R: usize, const C: usize>(m: &mut Matrix<T, R, C>, j: usize, l: usize) {
+    for row in m.0.iter_mut() {
+        row.swap(j, l);
+    }
+}
+
+// `x / p` only overflows for `MIN / -1`, which is exact
+fn divides<T: Integer>(p: T, x: T) -> bool {
+    x.checked_div(p).is_none_or(|q| q * p == x)
+}
+
+fn floor_div<T: Integer>(a: T, b: T) -> Result<T, LinalgError> {
+    let q = checked(a.checked_div(b))?;
+    if !divides(b, a) && (a < T::default()) != (b < T::default()) {
+        checked(q.checked_sub(T::one()))
+    } else {
+        Ok(q)
+    }
+}
+
+impl<T: Integer, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Row-style Hermite normal form, returns `(H, U)` with `UA = H` and `U`
+    /// unimodular.
+    ///
+    /// `H` is in row echelon form, its pivots are positive and the entries
+    /// above each pivot lie in `[0, pivot)`. Overflow of `T` is reported as
+    /// an error.
+    pub fn hermite_normal_form(&self) -> Result<(Self, Matrix<T, R, R>), LinalgError> {
+        let zero = T::default();
+        let one = T::one();
+        let mut h = *self;
+        let mut u = Matrix::identity();
+        let mut row = 0;
+
+        for col in 0..C {
+            if row == R {
+                break;
+            }
+            for i in row + 1..R {
+                let (a, b) = (h[(row, col)], h[(i, col)]);
+                if b == zero {
+                    continue;
+                }
+                let (g, x, y) = extended_gcd(a, b)?;
+                let ops = [x, y, neg(checked(b.checked_div(g))?)?, checked(a.checked_div(g))?];
+                combine_rows(&mut h, (row, i), ops)?;
+                combine_rows(&mut u, (row, i), ops)?;
+            }
+            let pivot = h[(row, col)];
+            if pivot == zero {
+                continue;
+            }
+            if pivot < zero {
+                negate_row(&mut h, row)?;
+                negate_row(&mut u, row)?;
+            }
+            let pivot = h[(row, col)];
+            for i in 0..row {
+                let q = floor_div(h[(i, col)], pivot)?;
+                combine_rows(&mut h, (i, row), [one, neg(q)?, zero, one])?;
+                combine_rows(&mut u, (i, row), [one, neg(q)?, zero, one])?;
+            }
+            row += 1;
+        }
+        Ok((h, u))
+    }
+
+    /// Smith normal form, returns `(S, U, V)` with `UAV = S` and `U`, `V`
+    /// unimodular.
+    ///
+    /// `S` is diagonal with nonnegative entries, each dividing the next.
+    /// Overflow of `T` is reported as an error.
+    #[allow(clippy::type_complexity)]
+    pub fn smith_normal_form(&self) -> Result<(Self, Matrix<T, R, R>, Matrix<T, C, C>), LinalgError> {
+        let zero = T::default();
+        let one = T::one();
+        // compares magnitudes without negating `MIN`
+        let nabs = |v: T| if v > zero { -v } else { v };
+        let mut s = *self;
+        let mut u = Matrix::identity();
+        let mut v = Matrix::identity();
+
+        for t in 0..R.min(C) {
+            loop {
+                // smallest nonzero entry of the trailing block as the pivot
+                let mut best: Option<(usize, usize)> = None;
+                for i in t..R {
+                    for j in t..C {
+                        if s[(i, j)] != zero && best.is_none_or(|b| nabs(s[(i, j)]) > nabs(s[b])) {
+                            best = Some((i, j));
+                        }
+                    }
+                }
+                let Some((pi, pj)) = best else {
+                    return Ok((s, u, v));
+                };
+                s.0.swap(t, pi);
+                u.0.swap(t, pi);
+                swap_cols(&mut s, t, pj);
+                swap_cols(&mut v, t, pj);
+
+                let pivot = s[(t, t)];
+                let mut clean = true;
+                for i in t + 1..R {
+                    let q = checked(s[(i, t)].checked_div(pivot))?;
+                    combine_rows(&mut s, (i, t), [one, neg(q)?, zero, one])?;
+                    combine_rows(&mut u, (i, t), [one, neg(q)?, zero, one])?;
+                    clean &= s[(i, t)] == zero;
+                }
+                for j in t + 1..C {
+                    let q = checked(s[(t, j)].checked_div(pivot))?;
+                    combine_cols(&mut s, (j, t), [one, neg(q)?, zero, one])?;
+                    combine_cols(&mut v, (j, t), [one, neg(q)?, zero, one])?;
+                    clean &= s[(t, j)] == zero;
+                }
+                if !clean {
+                    continue;
+                }
+
+                // the pivot has to divide the whole trailing block
+                let rest = (t + 1..R).find(|&i| (t + 1..C).any(|j| !divides(pivot, s[(i, j)])));
+                match rest {
+                    Some(i) => {
+                        combine_rows(&mut s, (t, i), [one, one, zero, one])?;
+                        combine_rows(&mut u, (t, i), [one, one, zero, one])?;
+                    }
+                    None => break,
+                }
+            }
+            if s[(t, t)] < zero {
+                negate_row(&mut s, t)?;
+                negate_row(&mut u, t)?;
+            }
+        }
+        Ok((s, u, v))
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::vector::Vector;
+
+    fn is_unimodular<const N: usize>(m: &Matrix<i64, N, N>) -> bool {
+        m.determinant_exact().unwrap().abs() == 1
+    }
+
+    #[test]
+    fn hermite_normal_form() {
+        let a = Matrix::from([[2i64, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+        let (h, u) = a.hermite_normal_form().unwrap();
+        assert_eq!(u.mul(a), h);
+        assert!(is_unimodular(&u));
+        let mut col = 0;
+        for row in 0..3 {
+            while h[(row, col)] == 0 {
+                assert!((row..3).all(|i| h[(i, col)] == 0));
+                col += 1;
+            }
+            assert!(h[(row, col)] > 0);
+            assert!((0..row).all(|i| (0..h[(row, col)]).contains(&h[(i, col)])));
+            assert!((row + 1..3).all(|i| h[(i, col)] == 0));
+        }
+
+        let (h, _) = Matrix::from([[3i64, 1], [5, 2]]).hermite_normal_form().unwrap();
+        assert_eq!(h, Matrix::identity());
+    }
+
+    #[test]
+    fn smith_normal_form() {
+        let a = Matrix::from([[2i64, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+        let (s, u, v) = a.smith_normal_form().unwrap();
+        assert_eq!(s, Matrix::from_diagonal(Vector::from([2, 6, 12])));
+        assert_eq!(u.mul(a).mul(v), s);
+        assert!(is_unimodular(&u) && is_unimodular(&v));
+
+        let (s, _, _) = Matrix::from([[2i64, 0], [0, 3]]).smith_normal_form().unwrap();
+        assert_eq!(s, Matrix::from_diagonal(Vector::from([1, 6])));
+
+        let a = Matrix::from([[0i64, 4, 6], [0, 2, 10]]);
+        let (s, u, v) = a.smith_normal_form().unwrap();
+        assert_eq!(s, Matrix::from([[2, 0, 0], [0, 14, 0]]));
+        assert_eq!(u.mul(a).mul(v), s);
+    }
+
+    #[test]
+    fn overflow() {
+        let a = Matrix::from([[i8::MAX, 1], [i8::MIN, 1]]);
+        assert_eq!(a.smith_normal_form().err(), Some(LinalgError::Overflow));
+    }
+
+    #[test]
+    fn gcd() {
+        assert_eq!(Ok(6), super::gcd(-12, 18));
+        assert_eq!(Ok(6), super::gcd(12, -18));
+        assert_eq!(Ok(5), super::gcd(0, -5));
+        assert_eq!(Ok(0), super::gcd(0, 0));
+        assert_eq!(Err(LinalgError::Overflow), super::gcd(i8::MIN, 0));
+    }
+}
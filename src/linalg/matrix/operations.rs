@@ -1,6 +1,6 @@
 use super::Matrix;
 
-use std::cmp::PartialEq;
+use core::cmp::PartialEq;
 impl<T: Default + PartialEq, const R: usize, const C: usize> PartialEq for Matrix<T, R, C> {
     fn eq(&self, other: &Self) -> bool {
         self.iter()
@@ -9,11 +9,12 @@ impl<T: Default + PartialEq, const R: usize, const C: usize> PartialEq for Matri
     }
 }
 
-use std::ops::Add;
+use core::ops::Add;
 impl<T, const R: usize, const C: usize> Matrix<T, R, C>
 where
     T: Default + Add<Output = T>   
 {
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, rhs: Self) -> Self {
         let mut m = Matrix::new();
         let mut self_mut = self;
@@ -35,22 +36,140 @@ where
     }
 }
 
-use std::ops::Mul;
+use core::ops::Sub;
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Default + Sub<Output = T>
+{
+    #[allow(clippy::should_implement_trait)]
+    pub fn sub(self, rhs: Self) -> Self {
+        let mut m = Matrix::new();
+        let mut self_mut = self;
+        let mut rhs_mut = rhs;
+        for ((row, col), mut val) in m.iter_mut().enumerate() {
+            *val = self_mut.replace((row, col), T::default()) - rhs_mut.replace((row, col), T::default());
+        }
+        m
+    }
+}
+impl<T, const R: usize, const C: usize> Sub<Matrix<T, R, C>> for Matrix<T, R, C>
+where
+    T: Default + Sub<Output = T>
+{
+    type Output = Matrix<T, R, C>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub(rhs)
+    }
+}
+
+use crate::linalg::scalar::Float;
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    pub fn frobenius_norm(&self) -> T {
+        T::sqrt(self.iter().fold(T::ZERO, |acc, &v| acc + v * v))
+    }
+}
+
+use core::ops::Mul;
 impl<T, const R: usize, const C: usize> Matrix<T, R, C>
 where
     T: Default + Mul<Output = T> + Copy + Add<Output = T>
 {
+    #[allow(clippy::should_implement_trait)]
     pub fn mul<const K: usize>(self, rhs: Matrix<T, C, K>) -> Matrix<T, R, K> {
         let mut m = Matrix::new();
-        for (row_num, row) in self.rows().enumerate() {
-            for (col_num, col) in rhs.cols().enumerate() {
-                m[(row_num, col_num)] = row.iter()
-                    .zip(col.iter())
-                    .map(|(&v1, &v2)| {v1 * v2})
-                    .fold(T::default(), |acc, v| acc + v);
+        mul_rows(&self.0, &rhs, &mut m.0);
+        m
+    }
+}
+
+#[cfg(feature = "alloc")]
+const BLOCK_ROWS: usize = 32;
+#[cfg(feature = "alloc")]
+const BLOCK_DEPTH: usize = 64;
+#[cfg(feature = "alloc")]
+const BLOCK_COLS: usize = 64;
+
+// Computes `out = lhs * rhs` for a band of rows. Every product goes through
+// here, so that all the multiplication paths sum in the same order: each
+// output element accumulates its terms in increasing `k`, starting from
+// `T::default()`.
+pub(crate) fn mul_rows<T, const C: usize, const K: usize>(lhs: &[[T; C]], rhs: &Matrix<T, C, K>, out: &mut [[T; K]])
+where
+    T: Default + Mul<Output = T> + Copy + Add<Output = T>
+{
+    mul_flat(lhs.as_flattened(), rhs.0.as_flattened(), out.as_flattened_mut(), C, K);
+}
+
+// `mul_rows` on row-major slices whose sizes are only known at run time:
+// `lhs` is `rows x depth`, `rhs` is `depth x cols` and `out` is `rows x
+// cols`, with `rows` taken from `out`.
+//
+// Products whose `rhs` does not fit in a single panel are tiled, see
+// `mul_flat_tiled`. Smaller ones, and all of them without `alloc`, use the
+// plain loop.
+pub(crate) fn mul_flat<T>(lhs: &[T], rhs: &[T], out: &mut [T], depth: usize, cols: usize)
+where
+    T: Default + Mul<Output = T> + Copy + Add<Output = T>
+{
+    out.fill(T::default());
+    if depth == 0 || cols == 0 {
+        return;
+    }
+    #[cfg(feature = "alloc")]
+    if depth > BLOCK_DEPTH || cols > BLOCK_COLS {
+        return mul_flat_tiled(lhs, rhs, out, depth, cols);
+    }
+    for (lhs_row, out_row) in lhs.chunks(depth).zip(out.chunks_mut(cols)) {
+        for (&a, rhs_row) in lhs_row.iter().zip(rhs.chunks(cols)) {
+            for (val, &b) in out_row.iter_mut().zip(rhs_row.iter()) {
+                *val = *val + a * b;
+            }
+        }
+    }
+}
+
+// A `BLOCK_DEPTH x BLOCK_COLS` panel of `rhs` and a `BLOCK_ROWS x
+// BLOCK_DEPTH` panel of `lhs` are copied into contiguous heap buffers and
+// reused from cache. The buffers are clamped to the operand sizes and kept
+// off the stack, which large matrices already fill.
+#[cfg(feature = "alloc")]
+fn mul_flat_tiled<T>(lhs: &[T], rhs: &[T], out: &mut [T], depth: usize, cols: usize)
+where
+    T: Default + Mul<Output = T> + Copy + Add<Output = T>
+{
+    let rows = (lhs.len() / depth).min(out.len() / cols);
+    let (panel_rows, panel_depth, panel_cols) = (BLOCK_ROWS.min(rows), BLOCK_DEPTH.min(depth), BLOCK_COLS.min(cols));
+    let mut lhs_panel = alloc::vec![T::default(); panel_rows * panel_depth];
+    let mut rhs_panel = alloc::vec![T::default(); panel_depth * panel_cols];
+
+    for col_start in (0..cols).step_by(BLOCK_COLS) {
+        let block_cols = BLOCK_COLS.min(cols - col_start);
+        for depth_start in (0..depth).step_by(BLOCK_DEPTH) {
+            let block_depth = BLOCK_DEPTH.min(depth - depth_start);
+            for (k, panel_row) in rhs_panel.chunks_mut(panel_cols).take(block_depth).enumerate() {
+                let start = (depth_start + k) * cols + col_start;
+                panel_row[..block_cols].copy_from_slice(&rhs[start..start + block_cols]);
+            }
+
+            for row_start in (0..rows).step_by(BLOCK_ROWS) {
+                let block_rows = BLOCK_ROWS.min(rows - row_start);
+                for (i, panel_row) in lhs_panel.chunks_mut(panel_depth).take(block_rows).enumerate() {
+                    let start = (row_start + i) * depth + depth_start;
+                    panel_row[..block_depth].copy_from_slice(&lhs[start..start + block_depth]);
+                }
+
+                let out_rows = out[row_start * cols..(row_start + block_rows) * cols].chunks_mut(cols);
+                for (lhs_row, out_row) in lhs_panel.chunks(panel_depth).zip(out_rows) {
+                    let out_row = &mut out_row[col_start..col_start + block_cols];
+                    for (&a, rhs_row) in lhs_row[..block_depth].iter().zip(rhs_panel.chunks(panel_cols)) {
+                        for (val, &b) in out_row.iter_mut().zip(rhs_row[..block_cols].iter()) {
+                            *val = *val + a * b;
+                        }
+                    }
+                }
             }
         }
-        m
     }
 }
 impl<T, const R: usize, const C: usize, const K: usize> Mul<Matrix<T, C, K>> for Matrix<T, R, C>
@@ -69,7 +188,7 @@ where
     T: Default + Mul<Output = T> + Copy + Add<Output = T>
 {
     pub fn mul_scal(&self, rhs: T) -> Matrix<T, R, C> {
-        self.clone()
+        (*self)
             .into_iter()
             .map(|v| v * rhs)
             .collect::<Matrix<T, R, C>>()
@@ -87,6 +206,30 @@ where
     }
 }
 
+use crate::linalg::vector::Vector;
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Default + Mul<Output = T> + Copy + Add<Output = T>
+{
+    pub fn mul_vector(&self, rhs: &Vector<T, C>) -> Vector<T, R> {
+        Vector::from_fn(|row| {
+            self.0[row].iter()
+                .zip(rhs.iter())
+                .fold(T::default(), |acc, (&v1, &v2)| acc + v1 * v2)
+        })
+    }
+}
+impl<T, const R: usize, const C: usize> Mul<Vector<T, C>> for Matrix<T, R, C>
+where
+    T: Default + Copy + Mul<Output = T> + Add<Output = T>
+{
+    type Output = Vector<T, R>;
+
+    fn mul(self, rhs: Vector<T, C>) -> Self::Output {
+        self.mul_vector(&rhs)
+    }
+}
+
 // unit tests
 
 #[cfg(test)] 
@@ -107,6 +250,19 @@ mod tests {
         assert_eq!(m, m1 + m2);
     }
 
+    #[test]
+    fn sub() {
+        let m1 = Matrix::from([[3, 3], [3, 3]]);
+        let m2 = Matrix::from([[1, 2], [3, 4]]);
+        let m = Matrix::from([[2, 1], [0, -1]]);
+        assert_eq!(m, m1 - m2);
+    }
+    #[test]
+    fn frobenius_norm() {
+        let m = Matrix::from([[3.0, 0.0], [0.0, 4.0]]);
+        assert_eq!(5.0, m.frobenius_norm());
+    }
+
     #[test]
     fn mul() {
         let m1 = Matrix::from([[1, 1, 1], [1, 1, 1]]);
@@ -115,6 +271,46 @@ mod tests {
         assert_eq!(m, m1 * m2);
     }
     #[test]
+    fn mul_rows_large_on_small_stack() {
+        // a tiled product of large operands, kept on the heap, needs no more
+        // than a small thread stack
+        let handle = std::thread::Builder::new()
+            .stack_size(32 * 1024)
+            .spawn(|| {
+                // SAFETY: all-zero bits are a valid matrix of `f64`
+                let zeros = || -> Box<Matrix<f64, 256, 256>> { unsafe { Box::new_zeroed().assume_init() } };
+                let (mut lhs, mut rhs, mut out) = (zeros(), zeros(), zeros());
+                for i in 0..256 {
+                    rhs[(i, i)] = 2.0;
+                    for j in 0..256 {
+                        lhs[(i, j)] = (i + j) as f64;
+                    }
+                }
+                mul_rows(&lhs.0, &rhs, &mut out.0);
+                (0..256).all(|i| (0..256).all(|j| out[(i, j)] == 2.0 * (i + j) as f64))
+            })
+            .unwrap();
+        assert!(handle.join().unwrap());
+    }
+    #[test]
+    fn mul_blocked() {
+        // larger than one tile in every direction, with ragged edges
+        let m1: Matrix<f64, 70, 90> = Matrix::from_fn(|i, j| ((i * 90 + j) as f64 * 0.37).sin());
+        let m2: Matrix<f64, 90, 75> = Matrix::from_fn(|i, j| ((i * 75 + j) as f64 * 0.11).cos());
+        let m = m1 * m2;
+        for i in 0..70 {
+            for j in 0..75 {
+                let expected = (0..90).fold(0.0, |acc, k| acc + m1[(i, k)] * m2[(k, j)]);
+                assert_eq!(expected.to_bits(), m[(i, j)].to_bits());
+            }
+        }
+    }
+    #[test]
+    fn mul_vector() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(Vector::from([6, 15]), m * Vector::from([1, 1, 1]));
+    }
+    #[test]
     fn mul_scal() {
         let m1 = Matrix::from([[1, 1], [1, 1]]);
         let m = Matrix::from([[2, 2], [2, 2]]);
@@ -0,0 +1,100 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::{orthogonal, Vector};
+
+const POLAR_MAX_ITERATIONS: usize = 100;
+
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Orthonormalizes the columns in place with modified Gram–Schmidt.
+    ///
+    /// Columns that depend on the previous ones are zeroed and the rank is
+    /// reported through `LinalgError::RankDeficient`.
+    pub fn gram_schmidt(&mut self) -> Result<(), LinalgError> {
+        let mut cols = self.transpose().0.map(Vector::from);
+        let result = orthogonal::gram_schmidt(&mut cols);
+        *self = Matrix::from_cols(cols);
+        result
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Polar decomposition `A = UP` of a nonsingular matrix.
+    ///
+    /// `U` is the orthogonal matrix nearest to `A` in the Frobenius norm and
+    /// `P` is symmetric positive definite. Computed with the scaled Newton
+    /// iteration `U = (γU + U^-T / γ) / 2`.
+    pub fn polar(&self) -> Result<(Self, Self), LinalgError> {
+        let half = T::ONE / (T::ONE + T::ONE);
+        let tol = T::EPSILON * T::from_usize(N);
+        let mut u = *self;
+        let mut scaling = true;
+
+        for _ in 0..POLAR_MAX_ITERATIONS {
+            let inv_t = u.inverse()?.transpose();
+            let gamma = if scaling {
+                T::sqrt(inv_t.frobenius_norm() / u.frobenius_norm())
+            } else {
+                T::ONE
+            };
+            let next = (u * gamma + inv_t * (T::ONE / gamma)) * half;
+            let diff = (next - u).frobenius_norm();
+            u = next;
+
+            // convergence is quadratic, so once the step is below the square
+            // root of the tolerance one more unscaled step is enough
+            if diff <= T::sqrt(tol) * u.frobenius_norm() {
+                if !scaling {
+                    let p = u.transpose() * *self;
+                    let p = (p + p.transpose()) * half;
+                    return Ok((u, p));
+                }
+                scaling = false;
+            }
+        }
+        Err(LinalgError::NoConvergence { iterations: POLAR_MAX_ITERATIONS })
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotation(angle: f64) -> Matrix<f64, 2, 2> {
+        let (s, c) = angle.sin_cos();
+        Matrix::from([[c, -s], [s, c]])
+    }
+    fn is_orthogonal(m: &Matrix<f64, 2, 2>) -> bool {
+        (m.transpose() * *m - Matrix::identity()).frobenius_norm() < 1e-12
+    }
+
+    #[test]
+    fn gram_schmidt() {
+        let mut m = rotation(0.3) * 1.01 + Matrix::from([[0.0, 0.02], [0.0, 0.0]]);
+        assert_eq!(Ok(()), m.gram_schmidt());
+        assert!(is_orthogonal(&m));
+    }
+    #[test]
+    fn gram_schmidt_rank_deficient() {
+        let mut m = Matrix::from([[1.0, 2.0], [1.0, 2.0]]);
+        assert_eq!(Err(LinalgError::RankDeficient { rank: 1 }), m.gram_schmidt());
+        assert_eq!(0.0, m[(0, 1)]);
+        assert_eq!(0.0, m[(1, 1)]);
+    }
+    #[test]
+    fn polar() {
+        let p = Matrix::from([[2.0, 0.5], [0.5, 1.0]]);
+        let a = rotation(0.7) * p;
+        let (u, h) = a.polar().unwrap();
+        assert!(is_orthogonal(&u));
+        assert!((u - rotation(0.7)).frobenius_norm() < 1e-12);
+        assert!((h - p).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn polar_singular() {
+        let a = Matrix::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(Err(LinalgError::Singular), a.polar());
+    }
+}
@@ -0,0 +1,57 @@
+use super::Matrix;
+use super::operations::mul_rows;
+use core::ops::{Add, Mul};
+use std::thread;
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C>
+where
+    T: Default + Mul<Output = T> + Copy + Add<Output = T> + Send + Sync
+{
+    /// `mul` with the output rows split across all available cores.
+    pub fn mul_parallel<const K: usize>(self, rhs: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        self.mul_parallel_with(rhs, threads)
+    }
+    /// `mul` with the output rows split across `threads` scoped threads.
+    ///
+    /// Each element is summed in the same order as in `mul`, so the result is
+    /// bit-identical to the serial product.
+    pub fn mul_parallel_with<const K: usize>(self, rhs: Matrix<T, C, K>, threads: usize) -> Matrix<T, R, K> {
+        let mut m = Matrix::new();
+        let threads = threads.clamp(1, R.max(1));
+        let rows_per_thread = R.div_ceil(threads).max(1);
+        let rhs = &rhs;
+
+        thread::scope(|s| {
+            for (lhs, out) in self.0.chunks(rows_per_thread).zip(m.0.chunks_mut(rows_per_thread)) {
+                s.spawn(move || mul_rows(lhs, rhs, out));
+            }
+        });
+        m
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_identical() {
+        let a: Matrix<f64, 7, 5> = Matrix::from_fn(|i, j| ((i * 5 + j) as f64).sin());
+        let b: Matrix<f64, 5, 6> = Matrix::from_fn(|i, j| ((i * 6 + j) as f64 * 0.3).cos());
+        let serial = a * b;
+        for threads in [1, 2, 3, 7, 16] {
+            let m = a.mul_parallel_with(b, threads);
+            assert!(serial.iter().zip(m.iter()).all(|(x, y)| x.to_bits() == y.to_bits()));
+        }
+        assert_eq!(serial, a.mul_parallel(b));
+    }
+    #[test]
+    fn integers() {
+        let m1 = Matrix::from([[1, 1, 1], [1, 1, 1]]);
+        let m = Matrix::from([[3, 3], [3, 3]]);
+        assert_eq!(m, m1.mul_parallel_with(m1.transpose(), 2));
+    }
+}
@@ -0,0 +1,174 @@
+use super::Matrix;
+use crate::linalg::scalar::One;
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "alloc")]
+use super::exact::{bareiss_step, checked};
+#[cfg(feature = "alloc")]
+use crate::linalg::{error::LinalgError, scalar::Integer};
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+// Polynomials are stored as coefficient vectors in increasing degree,
+// `p(x) = p[0] + p[1] x + ... + p[M - 1] x^(M - 1)`.
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + One
+{
+    /// Coefficients of `det(xI - A)` by the Faddeev–LeVerrier recurrence,
+    /// `M` must be `N + 1`.
+    ///
+    /// The recurrence only divides by `1..=N`, and those divisions are exact
+    /// for integer matrices, so the result is exact for integer types too.
+    pub fn characteristic_polynomial<const M: usize>(&self) -> Vector<T, M> {
+        const { assert!(M == N + 1, "characteristic_polynomial: M must equal N + 1") };
+        let mut c = Vector::from_fn(|_| T::default());
+        c[N] = T::one();
+
+        // M_k = A M_(k-1) + c_(N-k+1) I, c_(N-k) = -tr(A M_k) / k
+        let mut m: Matrix<T, N, N> = Matrix::new();
+        let mut k_t = T::default();
+        for k in 1..=N {
+            k_t = k_t + T::one();
+            m = *self * m;
+            for i in 0..N {
+                m[(i, i)] = m[(i, i)] + c[N - k + 1];
+            }
+            let am = *self * m;
+            let trace = (0..N).fold(T::default(), |acc, i| acc + am[(i, i)]);
+            c[N - k] = -(trace / k_t);
+        }
+        c
+    }
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T> + One
+{
+    /// `p(A)` by Horner's rule.
+    pub fn eval_polynomial<const M: usize>(&self, p: &Vector<T, M>) -> Self {
+        let mut result = Matrix::new();
+        for k in (0..M).rev() {
+            result = result * *self;
+            for i in 0..N {
+                result[(i, i)] = result[(i, i)] + p[k];
+            }
+        }
+        result
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Integer, const N: usize> Matrix<T, N, N> {
+    /// Monic polynomial of least degree with `p(A) = 0`, padded with zeros
+    /// above its degree. `M` must be `N + 1`.
+    ///
+    /// Finds the first power `A^d` that depends on `I, A, ..., A^(d-1)` by
+    /// fraction-free elimination on the `N² x (N + 1)` matrix of vectorized
+    /// powers. Overflow of `T` is reported instead of wrapping.
+    pub fn minimal_polynomial<const M: usize>(&self) -> Result<Vector<T, M>, LinalgError> {
+        const { assert!(M == N + 1, "minimal_polynomial: M must equal N + 1") };
+        let rows = N * N;
+
+        // k[row][j] is entry `row` of vec(A^j)
+        let mut k = vec![[T::default(); M]; rows];
+        let mut power: Matrix<T, N, N> = Matrix::identity();
+        for j in 0..M {
+            for (entry, &v) in k.iter_mut().zip(power.0.as_flattened()) {
+                entry[j] = v;
+            }
+            if j + 1 < M {
+                let mut next = Matrix::new();
+                for i in 0..N {
+                    for l in 0..N {
+                        let mut acc = T::default();
+                        for m in 0..N {
+                            acc = checked(acc.checked_add(checked(power[(i, m)].checked_mul(self[(m, l)]))?))?;
+                        }
+                        next[(i, l)] = acc;
+                    }
+                }
+                power = next;
+            }
+        }
+
+        // Bareiss elimination until the first column without a pivot
+        let zero = T::default();
+        let mut prev = T::one();
+        let mut degree = M - 1;
+        for j in 0..M {
+            let Some(p) = (j..rows).find(|&row| k[row][j] != zero) else {
+                degree = j;
+                break;
+            };
+            k.swap(j, p);
+            bareiss_step(&mut k, (j, j), prev)?;
+            prev = k[j][j];
+        }
+
+        // A^d = sum x_i A^i, the x_i are integers so every division is exact
+        let mut x: Vector<T, M> = Vector::from_fn(|_| zero);
+        for i in (0..degree).rev() {
+            let mut sum = k[i][degree];
+            for l in i + 1..degree {
+                sum = checked(sum.checked_sub(checked(k[i][l].checked_mul(x[l]))?))?;
+            }
+            x[i] = checked(sum.checked_div(k[i][i]))?;
+        }
+
+        let mut p = Vector::from_fn(|i| if i < degree { -x[i] } else { zero });
+        p[degree] = T::one();
+        Ok(p)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn characteristic_polynomial() {
+        let m = Matrix::from([[2, 1], [1, 2]]);
+        assert_eq!(Vector::from([3, -4, 1]), m.characteristic_polynomial());
+        let m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(Vector::from([-2.0, -5.0, 1.0]), m.characteristic_polynomial());
+    }
+    #[test]
+    fn cayley_hamilton() {
+        let m = Matrix::from([[2, -1, 3, 0], [1, 0, 4, -2], [5, 1, -3, 1], [0, 2, 1, 1]]);
+        let p: Vector<_, 5> = m.characteristic_polynomial();
+        assert_eq!(Matrix::zeros(), m.eval_polynomial(&p));
+    }
+    #[test]
+    fn eval_polynomial() {
+        let m = Matrix::from([[1, 1], [0, 1]]);
+        // 1 + 2x + x^2
+        assert_eq!(Matrix::from([[4, 4], [0, 4]]), m.eval_polynomial(&Vector::from([1, 2, 1])));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn minimal_polynomial() {
+        let i: Matrix<i64, 3, 3> = Matrix::identity();
+        assert_eq!(Ok(Vector::from([-1, 1, 0, 0])), i.minimal_polynomial());
+
+        let d = Matrix::from([[2, 0, 0], [0, 3, 0], [0, 0, 2]]);
+        assert_eq!(Ok(Vector::from([6, -5, 1, 0])), d.minimal_polynomial());
+
+        let jordan = Matrix::from([[2, 1, 0], [0, 2, 0], [0, 0, 2]]);
+        assert_eq!(Ok(Vector::from([4, -4, 1, 0])), jordan.minimal_polynomial());
+
+        let m = Matrix::from([[2, -1, 3], [1, 0, 4], [5, 1, -3]]);
+        assert_eq!(Ok(m.characteristic_polynomial::<4>()), m.minimal_polynomial());
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn minimal_polynomial_overflow() {
+        let m = Matrix::from([[100i8, 1], [1, 100]]);
+        assert_eq!(Err(LinalgError::Overflow), m.minimal_polynomial::<3>());
+    }
+}
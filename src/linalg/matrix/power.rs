@@ -0,0 +1,88 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::{Float, One};
+use core::ops::{Add, Mul};
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T> + One
+{
+    /// `A^n` by binary exponentiation, `A^0` is the identity.
+    pub fn pow(&self, n: u32) -> Self {
+        let mut result = Matrix::identity();
+        let mut base = *self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// `A^n`, negative powers go through the inverse and fail for singular
+    /// matrices.
+    pub fn powi(&self, n: i32) -> Result<Self, LinalgError> {
+        if n < 0 {
+            Ok(self.inverse()?.pow(n.unsigned_abs()))
+        } else {
+            Ok(self.pow(n as u32))
+        }
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq)]
+    struct Mod7(u8);
+    impl Add for Mod7 {
+        type Output = Mod7;
+        fn add(self, rhs: Self) -> Self::Output {
+            Mod7((self.0 + rhs.0) % 7)
+        }
+    }
+    impl Mul for Mod7 {
+        type Output = Mod7;
+        fn mul(self, rhs: Self) -> Self::Output {
+            Mod7((self.0 * rhs.0) % 7)
+        }
+    }
+    impl One for Mod7 {
+        fn one() -> Self {
+            Mod7(1)
+        }
+    }
+
+    #[test]
+    fn pow() {
+        let fib = Matrix::from([[1u64, 1], [1, 0]]);
+        assert_eq!(Matrix::identity(), fib.pow(0));
+        assert_eq!(fib, fib.pow(1));
+        assert_eq!(Matrix::from([[89, 55], [55, 34]]), fib.pow(10));
+    }
+    #[test]
+    fn pow_modular() {
+        let m = Matrix::from([[Mod7(1), Mod7(1)], [Mod7(1), Mod7(0)]]);
+        // F(16) = 987 = 0 mod 7, F(17) = 1597 = 1 mod 7, F(15) = 610 = 1 mod 7
+        assert_eq!(Matrix::from([[Mod7(1), Mod7(0)], [Mod7(0), Mod7(1)]]), m.pow(16));
+    }
+    #[test]
+    fn powi() {
+        let m = Matrix::from([[2.0, 0.0], [0.0, 4.0]]);
+        assert_eq!(Matrix::from([[0.125, 0.0], [0.0, 1.0 / 64.0]]), m.powi(-3).unwrap());
+        assert_eq!(Matrix::from([[8.0, 0.0], [0.0, 64.0]]), m.powi(3).unwrap());
+        let singular = Matrix::from([[1.0, 1.0], [1.0, 1.0]]);
+        assert_eq!(Err(LinalgError::Singular), singular.powi(-1));
+        assert!(singular.powi(2).is_ok());
+    }
+}
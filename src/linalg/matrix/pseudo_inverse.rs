@@ -0,0 +1,172 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+
+const JACOBI_MAX_SWEEPS: usize = 64;
+
+// Pseudo-inverse through the one-sided Jacobi SVD `A = U Σ Vᵀ`. Column pairs
+// of `A` are rotated until they are mutually orthogonal, the accumulated
+// rotations form `V` and the column norms are the singular values. Fails
+// if the last of `max_sweeps` sweeps still had to rotate.
+fn jacobi_pinv<T: Float, const R: usize, const C: usize>(
+    a: &Matrix<T, R, C>,
+    tol: T,
+    max_sweeps: usize,
+) -> Result<(Matrix<T, C, R>, usize), LinalgError> {
+    let two = T::ONE + T::ONE;
+    let mut u = *a;
+    let mut v: Matrix<T, C, C> = Matrix::identity();
+
+    let mut converged = false;
+    for _ in 0..max_sweeps {
+        let mut rotated = false;
+        for p in 0..C {
+            for q in p + 1..C {
+                let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                for i in 0..R {
+                    alpha = alpha + u[(i, p)] * u[(i, p)];
+                    beta = beta + u[(i, q)] * u[(i, q)];
+                    gamma = gamma + u[(i, p)] * u[(i, q)];
+                }
+                if gamma.abs() <= T::EPSILON * T::sqrt(alpha * beta) {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = T::ONE / (zeta.abs() + T::sqrt(T::ONE + zeta * zeta));
+                let t = if zeta < T::ZERO { -t } else { t };
+                let c = T::ONE / T::sqrt(T::ONE + t * t);
+                let s = c * t;
+                for i in 0..R {
+                    let (x, y) = (u[(i, p)], u[(i, q)]);
+                    u[(i, p)] = c * x - s * y;
+                    u[(i, q)] = s * x + c * y;
+                }
+                for i in 0..C {
+                    let (x, y) = (v[(i, p)], v[(i, q)]);
+                    v[(i, p)] = c * x - s * y;
+                    v[(i, q)] = s * x + c * y;
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::NoConvergence { iterations: max_sweeps });
+    }
+
+    let sigma: [T; C] = core::array::from_fn(|j| {
+        T::sqrt((0..R).fold(T::ZERO, |acc, i| acc + u[(i, j)] * u[(i, j)]))
+    });
+    let max = sigma.iter().fold(T::ZERO, |acc, &s| if s > acc { s } else { acc });
+    let mut pinv = Matrix::new();
+    let mut rank = 0;
+    for (j, &s) in sigma.iter().enumerate() {
+        if s == T::ZERO || s <= tol * max {
+            continue;
+        }
+        rank += 1;
+        // u_j is σ_j times the left singular vector
+        let w = T::ONE / (s * s);
+        for row in 0..C {
+            for col in 0..R {
+                pinv[(row, col)] = pinv[(row, col)] + v[(row, j)] * u[(col, j)] * w;
+            }
+        }
+    }
+    Ok((pinv, rank))
+}
+
+impl<T: Float, const R: usize, const C: usize> Matrix<T, R, C> {
+    fn pseudo_inverse_with_rank(&self, tol: T, max_sweeps: usize) -> Result<(Matrix<T, C, R>, usize), LinalgError> {
+        // rotating the shorter side keeps the sweeps small
+        if R >= C {
+            jacobi_pinv(self, tol, max_sweeps)
+        } else {
+            let (pinv, rank) = jacobi_pinv(&self.transpose(), tol, max_sweeps)?;
+            Ok((pinv.transpose(), rank))
+        }
+    }
+
+    /// Moore–Penrose pseudo-inverse from a one-sided Jacobi SVD.
+    ///
+    /// Singular values at most `tol` times the largest one are treated as
+    /// zero, which makes the result well defined for rank-deficient `A`.
+    /// Fails with `LinalgError::NoConvergence` if the columns are still not
+    /// orthogonal after the last sweep.
+    pub fn pseudo_inverse(&self, tol: T) -> Result<Matrix<T, C, R>, LinalgError> {
+        Ok(self.pseudo_inverse_with_rank(tol, JACOBI_MAX_SWEEPS)?.0)
+    }
+
+    /// Minimum-norm least-squares solution of `Ax = b`, returned together
+    /// with the effective rank, see [`Matrix::pseudo_inverse`] for `tol`.
+    pub fn least_squares(&self, b: &Vector<T, R>, tol: T) -> Result<(Vector<T, C>, usize), LinalgError> {
+        let (pinv, rank) = self.pseudo_inverse_with_rank(tol, JACOBI_MAX_SWEEPS)?;
+        Ok((pinv.mul_vector(b), rank))
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_penrose<const R: usize, const C: usize>(a: Matrix<f64, R, C>, x: Matrix<f64, C, R>) {
+        assert!((a * x * a - a).frobenius_norm() < 1e-12);
+        assert!((x * a * x - x).frobenius_norm() < 1e-12);
+        assert!((a * x - (a * x).transpose()).frobenius_norm() < 1e-12);
+        assert!((x * a - (x * a).transpose()).frobenius_norm() < 1e-12);
+    }
+
+    #[test]
+    fn full_rank() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let x = a.pseudo_inverse(1e-12).unwrap();
+        assert!((x * a - Matrix::identity()).frobenius_norm() < 1e-12);
+        assert_penrose(a, x);
+
+        let m = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+        assert!((m.pseudo_inverse(1e-12).unwrap() - m.inverse().unwrap()).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn rank_deficient() {
+        let a = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+        assert_penrose(a, a.pseudo_inverse(1e-12).unwrap());
+        let (_, rank) = a.least_squares(&Vector::from([1.0, 2.0]), 1e-12).unwrap();
+        assert_eq!(1, rank);
+
+        let a = Matrix::from([[1.0, 0.0], [0.0, 1e-14], [0.0, 0.0]]);
+        let x = a.pseudo_inverse(1e-10).unwrap();
+        assert!((x - Matrix::from([[1.0, 0.0, 0.0], [0.0; 3]])).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn least_squares() {
+        // underdetermined, the minimum-norm solution splits evenly
+        let a = Matrix::from([[1.0, 1.0]]);
+        let (x, rank) = a.least_squares(&Vector::from([2.0]), 1e-12).unwrap();
+        assert_eq!(1, rank);
+        assert!(x.dist(&Vector::from([1.0, 1.0])) < 1e-12);
+
+        // overdetermined line fit through (0, 1), (1, 2), (2, 4)
+        let a = Matrix::from([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+        let (x, rank) = a.least_squares(&Vector::from([1.0, 2.0, 4.0]), 1e-12).unwrap();
+        assert_eq!(2, rank);
+        assert!(x.dist(&Vector::from([5.0 / 6.0, 1.5])) < 1e-12);
+    }
+    #[test]
+    fn no_convergence() {
+        let a = Matrix::from([[4.0, 1.0, 2.0], [1.0, 3.0, 0.5], [2.0, 0.5, 5.0]]);
+        assert_eq!(
+            Err(LinalgError::NoConvergence { iterations: 1 }),
+            a.pseudo_inverse_with_rank(1e-12, 1)
+        );
+        let (x, rank) = a.pseudo_inverse_with_rank(1e-12, JACOBI_MAX_SWEEPS).unwrap();
+        assert_eq!(3, rank);
+        assert_penrose(a, x);
+    }
+}
@@ -0,0 +1,49 @@
+use super::Matrix;
+use crate::linalg::vector::Vector;
+
+impl<T: Default, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Reinterprets the row-major data as a `R2 x C2` matrix.
+    pub fn reshape<const R2: usize, const C2: usize>(self) -> Matrix<T, R2, C2> {
+        const { assert!(R * C == R2 * C2, "reshape: R * C must equal R2 * C2") };
+        self.into_iter().collect()
+    }
+    /// Row-major flattening into a vector of length `R * C`.
+    pub fn flatten<const N: usize>(self) -> Vector<T, N> {
+        const { assert!(R * C == N, "flatten: R * C must equal N") };
+        self.into_iter().collect()
+    }
+}
+
+impl<T: Default, const L: usize> Vector<T, L> {
+    /// Fills a `R x C` matrix with the elements in row-major order.
+    pub fn into_matrix<const R: usize, const C: usize>(self) -> Matrix<T, R, C> {
+        const { assert!(R * C == L, "into_matrix: R * C must equal L") };
+        self.into_iter().collect()
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reshape() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(Matrix::from([[1, 2], [3, 4], [5, 6]]), m.reshape::<3, 2>());
+        assert_eq!(m, m.reshape::<6, 1>().reshape::<2, 3>());
+    }
+    #[test]
+    fn flatten() {
+        let m = Matrix::from([[String::from("a"), String::from("b")], [String::from("c"), String::from("d")]]);
+        let v: Vector<_, 4> = m.flatten();
+        assert_eq!("c", v[2]);
+    }
+    #[test]
+    fn into_matrix() {
+        let v = Vector::from([1, 2, 3, 4]);
+        assert_eq!(Matrix::from([[1, 2], [3, 4]]), v.into_matrix::<2, 2>());
+        assert_eq!(Matrix::from([[1], [2], [3], [4]]), v.into_matrix::<4, 1>());
+    }
+}
@@ -0,0 +1,606 @@
+use super::elementary::{Givens, Householder};
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+use crate::linalg::vector::Vector;
+
+const SCHUR_MAX_ITERATIONS: usize = 100;
+
+// `|a|` with the sign of `b`
+fn sign<T: Float>(a: T, b: T) -> T {
+    if b < T::ZERO {
+        -a.abs()
+    } else {
+        a.abs()
+    }
+}
+
+// Reflector `I - β v vᵀ` of length 2 or 3 mapping `x` to a multiple of `e₀`.
+fn small_reflector<T: Float>(x: [T; 3], len: usize) -> ([T; 3], T) {
+    let norm = T::sqrt(x[..len].iter().fold(T::ZERO, |acc, &v| acc + v * v));
+    if norm == T::ZERO {
+        return ([T::ZERO; 3], T::ZERO);
+    }
+    let alpha = -sign(norm, x[0]);
+    let beta = T::ONE / (norm * (norm + x[0].abs()));
+    ([x[0] - alpha, x[1], x[2]], beta)
+}
+
+fn reflect_rows<T: Float, const N: usize>(
+    m: &mut Matrix<T, N, N>,
+    (k, len): (usize, usize),
+    (v, beta): ([T; 3], T),
+    cols: core::ops::Range<usize>,
+) {
+    for col in cols {
+        let w = beta * (0..len).fold(T::ZERO, |acc, i| acc + v[i] * m[(k + i, col)]);
+        for i in 0..len {
+            m[(k + i, col)] = m[(k + i, col)] - v[i] * w;
+        }
+    }
+}
+
+fn reflect_cols<T: Float, const N: usize>(
+    m: &mut Matrix<T, N, N>,
+    (k, len): (usize, usize),
+    (v, beta): ([T; 3], T),
+    rows: core::ops::Range<usize>,
+) {
+    for row in rows {
+        let w = beta * (0..len).fold(T::ZERO, |acc, j| acc + m[(row, k + j)] * v[j]);
+        for j in 0..len {
+            m[(row, k + j)] = m[(row, k + j)] - w * v[j];
+        }
+    }
+}
+
+// Brings the 2×2 block at `k` to standard form (LAPACK's `dlanv2`): upper
+// triangular for real eigenvalues, otherwise `[a b; c a]` with `bc < 0`.
+fn standardize<T: Float, const N: usize>(t: &mut Matrix<T, N, N>, q: &mut Matrix<T, N, N>, k: usize) {
+    let half = T::ONE / (T::ONE + T::ONE);
+    let four = T::from_usize(4);
+    let (mut a, mut b, mut c, mut d) = (t[(k, k)], t[(k, k + 1)], t[(k + 1, k)], t[(k + 1, k + 1)]);
+    let (mut cs, mut sn) = (T::ONE, T::ZERO);
+
+    if c == T::ZERO {
+        // already upper triangular
+    } else if b == T::ZERO {
+        (cs, sn) = (T::ZERO, T::ONE);
+        (a, d) = (d, a);
+        (b, c) = (-c, T::ZERO);
+    } else if a - d == T::ZERO && (b < T::ZERO) != (c < T::ZERO) {
+        // already standard
+    } else {
+        let temp = a - d;
+        let p = half * temp;
+        let bcmax = if b.abs() > c.abs() { b.abs() } else { c.abs() };
+        let bcmis = sign(if b.abs() < c.abs() { b.abs() } else { c.abs() }, b) * sign(T::ONE, c);
+        let scale = if p.abs() > bcmax { p.abs() } else { bcmax };
+        let z = p / scale * p + bcmax / scale * bcmis;
+
+        if z >= four * T::EPSILON {
+            // real eigenvalues, make the block upper triangular
+            let z = p + sign(T::sqrt(scale) * T::sqrt(z), p);
+            a = d + z;
+            d = d - bcmax / z * bcmis;
+            let g = Givens::new(z, c);
+            (cs, sn) = (g.c, g.s);
+            b = b - c;
+            c = T::ZERO;
+        } else {
+            // complex or nearly equal real eigenvalues, equalize the diagonal
+            let sigma = b + c;
+            let tau = T::sqrt(sigma * sigma + temp * temp);
+            cs = T::sqrt(half * (T::ONE + sigma.abs() / tau));
+            sn = -(p / (tau * cs)) * sign(T::ONE, sigma);
+
+            let (aa, bb) = (a * cs + b * sn, -a * sn + b * cs);
+            let (cc, dd) = (c * cs + d * sn, -c * sn + d * cs);
+            a = aa * cs + cc * sn;
+            b = bb * cs + dd * sn;
+            c = -aa * sn + cc * cs;
+            d = -bb * sn + dd * cs;
+
+            let mean = half * (a + d);
+            a = mean;
+            d = mean;
+            if c != T::ZERO {
+                if b == T::ZERO {
+                    (b, c) = (-c, T::ZERO);
+                    (cs, sn) = (-sn, cs);
+                } else if (b < T::ZERO) == (c < T::ZERO) {
+                    let (sab, sac) = (T::sqrt(b.abs()), T::sqrt(c.abs()));
+                    let p = sign(sab * sac, c);
+                    let tau = T::ONE / T::sqrt((b + c).abs());
+                    a = mean + p;
+                    d = mean - p;
+                    b = b - c;
+                    c = T::ZERO;
+                    let (cs1, sn1) = (sab * tau, sac * tau);
+                    (cs, sn) = (cs * cs1 - sn * sn1, cs * sn1 + sn * cs1);
+                }
+            }
+        }
+    }
+
+    // T = PᵀTP with P = [cs -sn; sn cs] in the plane (k, k + 1)
+    Givens { c: cs, s: sn }.apply_left(t, k, k + 1);
+    Givens { c: cs, s: -sn }.apply_right(t, k, k + 1);
+    Givens { c: cs, s: -sn }.apply_right(q, k, k + 1);
+    (t[(k, k)], t[(k, k + 1)], t[(k + 1, k)], t[(k + 1, k + 1)]) = (a, b, c, d);
+}
+
+// Francis double-shift QR iteration on the Hessenberg matrix `t`,
+// accumulating the transformations into `q`.
+fn francis<T: Float, const N: usize>(
+    t: &mut Matrix<T, N, N>,
+    q: &mut Matrix<T, N, N>,
+) -> Result<(), LinalgError> {
+    let norm = t.frobenius_norm();
+    let mut hi = N;
+    let mut iterations = 0;
+
+    while hi > 0 {
+        let end = hi - 1;
+
+        // start of the unreduced block ending at `end`
+        let mut lo = end;
+        while lo > 0 {
+            let mut scale = t[(lo - 1, lo - 1)].abs() + t[(lo, lo)].abs();
+            if scale == T::ZERO {
+                scale = norm;
+            }
+            if t[(lo, lo - 1)].abs() <= T::EPSILON * scale {
+                t[(lo, lo - 1)] = T::ZERO;
+                break;
+            }
+            lo -= 1;
+        }
+
+        if lo == end {
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        if lo + 1 == end {
+            standardize(t, q, lo);
+            hi -= 2;
+            iterations = 0;
+            continue;
+        }
+
+        iterations += 1;
+        if iterations > SCHUR_MAX_ITERATIONS {
+            return Err(LinalgError::NoConvergence { iterations: SCHUR_MAX_ITERATIONS });
+        }
+
+        // shifts are the eigenvalues of the trailing 2×2 block, with an
+        // exceptional shift now and then to break cycles
+        let (s, p) = if iterations % 10 == 0 {
+            let w = t[(end, end - 1)].abs() + t[(end - 1, end - 2)].abs();
+            let s = T::from_usize(3) / (T::ONE + T::ONE) * w;
+            (s, w * w)
+        } else {
+            let (a, b) = (t[(end - 1, end - 1)], t[(end - 1, end)]);
+            let (c, d) = (t[(end, end - 1)], t[(end, end)]);
+            (a + d, a * d - b * c)
+        };
+
+        let mut x = t[(lo, lo)] * t[(lo, lo)] + t[(lo, lo + 1)] * t[(lo + 1, lo)] - s * t[(lo, lo)] + p;
+        let mut y = t[(lo + 1, lo)] * (t[(lo, lo)] + t[(lo + 1, lo + 1)] - s);
+        let mut z = t[(lo + 1, lo)] * t[(lo + 2, lo + 1)];
+
+        // chase the bulge down the diagonal
+        for k in lo..end - 1 {
+            let reflector = small_reflector([x, y, z], 3);
+            let first = if k > lo { k - 1 } else { lo };
+            reflect_rows(t, (k, 3), reflector, first..N);
+            reflect_cols(t, (k, 3), reflector, 0..(k + 4).min(hi));
+            reflect_cols(q, (k, 3), reflector, 0..N);
+            if k > lo {
+                t[(k + 1, k - 1)] = T::ZERO;
+                t[(k + 2, k - 1)] = T::ZERO;
+            }
+            x = t[(k + 1, k)];
+            y = t[(k + 2, k)];
+            if k + 3 < hi {
+                z = t[(k + 3, k)];
+            }
+        }
+        let reflector = small_reflector([x, y, T::ZERO], 2);
+        reflect_rows(t, (end - 1, 2), reflector, end - 2..N);
+        reflect_cols(t, (end - 1, 2), reflector, 0..hi);
+        reflect_cols(q, (end - 1, 2), reflector, 0..N);
+        t[(end, end - 2)] = T::ZERO;
+    }
+    Ok(())
+}
+
+/// Solves the Kronecker form `K vec(X) = vec(C)` of a small matrix equation
+/// for a `p×q` block `X` with `p, q ≤ 2`, stored row by row in `vec(X)`.
+///
+/// Nearly singular pivots are perturbed, as in LAPACK's `dlasy2`, so the
+/// solution stays finite. The flag reports whether that happened.
+pub(super) fn solve_kronecker<T: Float>(
+    mut k: [[T; 4]; 4],
+    c: &[[T; 2]; 2],
+    (p, q): (usize, usize),
+) -> ([[T; 2]; 2], bool) {
+    let n = p * q;
+    let mut rhs = [T::ZERO; 4];
+    for r in 0..p {
+        for col in 0..q {
+            rhs[r * q + col] = c[r][col];
+        }
+    }
+
+    let largest = k.iter().flatten().fold(T::ZERO, |acc, &v| if v.abs() > acc { v.abs() } else { acc });
+    let small = if largest == T::ZERO { T::EPSILON } else { T::EPSILON * largest };
+    let mut perturbed = false;
+    for col in 0..n {
+        let mut pivot = col;
+        for row in col + 1..n {
+            if k[row][col].abs() > k[pivot][col].abs() {
+                pivot = row;
+            }
+        }
+        k.swap(col, pivot);
+        rhs.swap(col, pivot);
+        if k[col][col].abs() < small {
+            k[col][col] = small;
+            perturbed = true;
+        }
+        for row in col + 1..n {
+            let factor = k[row][col] / k[col][col];
+            let pivot_row = k[col];
+            for (v, &w) in k[row][col..n].iter_mut().zip(&pivot_row[col..n]) {
+                *v = *v - factor * w;
+            }
+            rhs[row] = rhs[row] - factor * rhs[col];
+        }
+    }
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(rhs[row], |acc, j| acc - k[row][j] * rhs[j]);
+        rhs[row] = sum / k[row][row];
+    }
+
+    let mut x = [[T::ZERO; 2]; 2];
+    for r in 0..p {
+        for col in 0..q {
+            x[r][col] = rhs[r * q + col];
+        }
+    }
+    (x, perturbed)
+}
+
+/// Solves the small Sylvester equation `AX + XB = C` for a `p×p` block `A`
+/// and a `q×q` block `B`, see [`solve_kronecker`].
+pub(super) fn solve_small_sylvester<T: Float>(
+    (a, p): (&[[T; 2]; 2], usize),
+    (b, q): (&[[T; 2]; 2], usize),
+    c: &[[T; 2]; 2],
+) -> ([[T; 2]; 2], bool) {
+    let mut k = [[T::ZERO; 4]; 4];
+    for r in 0..p {
+        for col in 0..q {
+            let idx = r * q + col;
+            for s in 0..p {
+                k[idx][s * q + col] = k[idx][s * q + col] + a[r][s];
+            }
+            for s in 0..q {
+                k[idx][r * q + s] = k[idx][r * q + s] + b[s][col];
+            }
+        }
+    }
+    solve_kronecker(k, c, (p, q))
+}
+
+// size of the diagonal block starting at `k`
+pub(super) fn block_size<T: Float, const N: usize>(t: &Matrix<T, N, N>, k: usize) -> usize {
+    if k + 1 < N && t[(k + 1, k)] != T::ZERO {
+        2
+    } else {
+        1
+    }
+}
+
+// eigenvalue `(re, im)` of the standardized block at `k`, `im ≥ 0`
+fn block_eigenvalue<T: Float, const N: usize>(t: &Matrix<T, N, N>, k: usize) -> (T, T) {
+    if block_size(t, k) == 1 {
+        (t[(k, k)], T::ZERO)
+    } else {
+        (t[(k, k)], T::sqrt((t[(k, k + 1)] * t[(k + 1, k)]).abs()))
+    }
+}
+
+pub(super) fn block<T: Float, const N: usize>(
+    t: &Matrix<T, N, N>,
+    (row, col): (usize, usize),
+    (p, q): (usize, usize),
+) -> [[T; 2]; 2] {
+    let mut b = [[T::ZERO; 2]; 2];
+    for i in 0..p {
+        for j in 0..q {
+            b[i][j] = t[(row + i, col + j)];
+        }
+    }
+    b
+}
+
+// Swaps the adjacent blocks of sizes `p` and `q` starting at `j` by an
+// orthogonal similarity, following Bai and Demmel. The swap is rejected,
+// leaving `t` and `z` untouched, when the blocks are too close to separate:
+// the Sylvester solve needed a perturbation, the part of `t` that is zeroed
+// is not negligible, or the similarity does not reproduce the window.
+fn swap_blocks<T: Float, const N: usize>(
+    t: &mut Matrix<T, N, N>,
+    z: &mut Matrix<T, N, N>,
+    j: usize,
+    (p, q): (usize, usize),
+) -> Result<(), LinalgError> {
+    let n = p + q;
+    let window = j..j + n;
+    let norm = T::sqrt(
+        window
+            .clone()
+            .flat_map(|r| window.clone().map(move |c| (r, c)))
+            .fold(T::ZERO, |acc, (r, c)| acc + t[(r, c)] * t[(r, c)]),
+    );
+    let ten = T::from_usize(10);
+    let thresh = ten * T::EPSILON * norm;
+
+    let t11 = block(t, (j, j), (p, p));
+    let t22 = block(t, (j + p, j + p), (q, q)).map(|row| row.map(|v| -v));
+    let t12 = block(t, (j, j + p), (p, q));
+    // the columns of [-X; I] span the invariant subspace of T22
+    let (x, perturbed) = solve_small_sylvester((&t11, p), (&t22, q), &t12);
+    if perturbed {
+        return Err(LinalgError::Singular);
+    }
+
+    let mut basis = [Vector::from_value(T::ZERO); 2];
+    for (c, col) in basis.iter_mut().enumerate().take(q) {
+        for r in 0..p {
+            col[j + r] = -x[r][c];
+        }
+        col[j + p + c] = T::ONE;
+    }
+    let mut swapped = *t;
+    let mut swapped_z = *z;
+    let mut local = Matrix::identity();
+    for c in 0..q {
+        let h = Householder::from_tail(&basis[c], j + c);
+        h.apply_left(&mut swapped);
+        h.apply_right(&mut swapped);
+        h.apply_right(&mut swapped_z);
+        h.apply_right(&mut local);
+        for later in basis.iter_mut().skip(c + 1).take(q - c - 1) {
+            h.apply_vector(later);
+        }
+    }
+
+    for row in j + q..j + n {
+        for col in j..j + q {
+            if swapped[(row, col)].abs() > thresh {
+                return Err(LinalgError::Singular);
+            }
+            swapped[(row, col)] = T::ZERO;
+        }
+    }
+    // ‖QᵀTQ - T̃‖ on the window, `local` is the identity outside of it
+    let mut residual = T::ZERO;
+    for a in window.clone() {
+        for b in window.clone() {
+            let mut v = -swapped[(a, b)];
+            for r in window.clone() {
+                for s in window.clone() {
+                    v = v + local[(r, a)] * t[(r, s)] * local[(s, b)];
+                }
+            }
+            residual = residual + v * v;
+        }
+    }
+    if T::sqrt(residual) > thresh {
+        return Err(LinalgError::Singular);
+    }
+
+    *t = swapped;
+    *z = swapped_z;
+    if q == 2 {
+        standardize(t, z, j);
+    }
+    if p == 2 {
+        standardize(t, z, j + q);
+    }
+    Ok(())
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Real Schur decomposition, returns `(Q, T)` with `A = QTQᵀ`, `Q`
+    /// orthogonal and `T` quasi upper triangular.
+    ///
+    /// The diagonal blocks of `T` are 1×1 for real eigenvalues and 2×2 of the
+    /// form `[a b; c a]` with `bc < 0` for the complex pair `a ± i√(-bc)`.
+    /// Computed with the Francis double-shift QR iteration.
+    pub fn schur(&self) -> Result<(Self, Self), LinalgError> {
+        let (mut q, mut t) = self.hessenberg();
+        francis(&mut t, &mut q)?;
+        Ok((q, t))
+    }
+
+    /// Real Schur decomposition with the eigenvalues `(re, im)` accepted by
+    /// `select` moved to the leading block of `T`, returns `(Q, T, m)` where
+    /// `m` is the size of that block.
+    ///
+    /// A complex pair is passed to `select` once, with `im > 0`. The leading
+    /// `m` columns of `Q` span the invariant subspace of the selected
+    /// eigenvalues.
+    ///
+    /// Returns `LinalgError::Singular` when a selected eigenvalue is too
+    /// close to one it has to move past for the blocks to be swapped stably.
+    pub fn schur_ordered<F>(&self, select: F) -> Result<(Self, Self, usize), LinalgError>
+    where
+        F: Fn(T, T) -> bool,
+    {
+        let (mut q, mut t) = self.schur()?;
+        let mut selected = 0;
+        let mut k = 0;
+
+        while k < N {
+            let size = block_size(&t, k);
+            let (re, im) = block_eigenvalue(&t, k);
+            if select(re, im) {
+                let mut here = k;
+                while here > selected {
+                    let prev = if here >= 2 && t[(here - 1, here - 2)] != T::ZERO { 2 } else { 1 };
+                    swap_blocks(&mut t, &mut q, here - prev, (prev, size))?;
+                    here -= prev;
+                }
+                selected += size;
+            }
+            k += size;
+        }
+        Ok((q, t, selected))
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::matrix::special;
+
+    fn assert_schur<const N: usize>(a: &Matrix<f64, N, N>, q: &Matrix<f64, N, N>, t: &Matrix<f64, N, N>) {
+        assert!((q.transpose() * *q - Matrix::identity()).frobenius_norm() < 1e-12);
+        assert!((*q * *t * q.transpose() - *a).frobenius_norm() < 1e-10);
+        let mut k = 0;
+        while k < N {
+            assert!((k + 2..N).all(|i| t[(i, k)] == 0.0));
+            if block_size(t, k) == 2 {
+                assert_eq!(t[(k, k)], t[(k + 1, k + 1)]);
+                assert!(t[(k, k + 1)] * t[(k + 1, k)] < 0.0);
+                assert!(k + 2 == N || t[(k + 2, k + 1)] == 0.0);
+                k += 2;
+            } else {
+                k += 1;
+            }
+        }
+    }
+
+    fn eigenvalues<const N: usize>(t: &Matrix<f64, N, N>) -> [(f64, f64); N] {
+        let mut values = [(0.0, 0.0); N];
+        let mut k = 0;
+        while k < N {
+            let (re, im) = block_eigenvalue(t, k);
+            values[k] = (re, im);
+            if block_size(t, k) == 2 {
+                values[k + 1] = (re, -im);
+                k += 1;
+            }
+            k += 1;
+        }
+        values
+    }
+
+    fn close((a, b): (f64, f64), (c, d): (f64, f64)) -> bool {
+        (a - c).abs() < 1e-8 && (b - d).abs() < 1e-8
+    }
+
+    // (x - 1)(x + 2)(x² + 2x + 5)(x - 3), roots 1, -2, -1 ± 2i, 3
+    fn sample() -> Matrix<f64, 5, 5> {
+        // expand the product with ascending coefficients
+        let factors: [&[f64]; 4] = [&[-1.0, 1.0], &[2.0, 1.0], &[5.0, 2.0, 1.0], &[-3.0, 1.0]];
+        let mut poly = [0.0; 6];
+        poly[0] = 1.0;
+        let mut degree = 0;
+        for f in factors {
+            let mut next = [0.0; 6];
+            for (i, &p) in poly.iter().enumerate().take(degree + 1) {
+                for (j, &q) in f.iter().enumerate() {
+                    next[i + j] += p * q;
+                }
+            }
+            poly = next;
+            degree += f.len() - 1;
+        }
+        special::companion(&Vector::from_fn(|i| poly[i]))
+    }
+
+    #[test]
+    fn schur() {
+        let a = sample();
+        let (q, t) = a.schur().unwrap();
+        assert_schur(&a, &q, &t);
+        let values = eigenvalues(&t);
+        for expected in [(1.0, 0.0), (-2.0, 0.0), (-1.0, 2.0), (-1.0, -2.0), (3.0, 0.0)] {
+            assert!(values.iter().any(|&v| close(v, expected)));
+        }
+
+        let r = Matrix::from([[0.0, -1.0], [1.0, 0.0]]);
+        let (q, t) = r.schur().unwrap();
+        assert_schur(&r, &q, &t);
+        assert!(close((0.0, 1.0), block_eigenvalue(&t, 0)));
+    }
+    #[test]
+    fn schur_triangular() {
+        let a = Matrix::from([[1.0, 2.0, 3.0], [0.0, 4.0, 5.0], [0.0, 0.0, 6.0]]);
+        let (q, t) = a.schur().unwrap();
+        assert_schur(&a, &q, &t);
+        assert_eq!(a, t);
+    }
+    #[test]
+    fn schur_ordered() {
+        let a = sample();
+        let (q, t, m) = a.schur_ordered(|re, _| re < 0.0).unwrap();
+        assert_schur(&a, &q, &t);
+        assert_eq!(3, m);
+        let values = eigenvalues(&t);
+        assert!(values[..3].iter().all(|&(re, _)| re < 0.0));
+        assert!(values[3..].iter().all(|&(re, _)| re > 0.0));
+
+        let (q, t, m) = a.schur_ordered(|_, im| im > 0.0).unwrap();
+        assert_schur(&a, &q, &t);
+        assert_eq!(2, m);
+        assert!(close((-1.0, 2.0), block_eigenvalue(&t, 0)));
+    }
+    #[test]
+    fn small_sylvester() {
+        let a = [[1.0, 2.0], [-2.0, 1.0]];
+        let b = [[3.0, 0.0], [1.0, 4.0]];
+        let c = [[1.0, 2.0], [3.0, 4.0]];
+        let (x, perturbed) = solve_small_sylvester((&a, 2), (&b, 2), &c);
+        assert!(!perturbed);
+        let (a, b, x) = (Matrix::from(a), Matrix::from(b), Matrix::from(x));
+        assert!((a * x + x * b - Matrix::from(c)).frobenius_norm() < 1e-12);
+    }
+    #[test]
+    fn swap_close_eigenvalues() {
+        // complex pairs 1 ± i and (1 + δ) ± i, strongly coupled
+        let d = 1e-14;
+        let a = Matrix::from([
+            [1.0, 1.0, 1e4, 1e4],
+            [-1.0, 1.0, 1e4, 1e4],
+            [0.0, 0.0, 1.0 + d, 1.0],
+            [0.0, 0.0, -1.0, 1.0 + d],
+        ]);
+        let (mut t, mut q) = (a, Matrix::identity());
+        swap_blocks(&mut t, &mut q, 0, (2, 2)).unwrap();
+        assert_schur(&a, &q, &t);
+        assert!(block_eigenvalue(&t, 0).0 > block_eigenvalue(&t, 2).0);
+
+        let (mut t, mut q) = (a, Matrix::identity());
+        t[(2, 2)] = 1.0;
+        t[(3, 3)] = 1.0;
+        let equal = t;
+        assert_eq!(Err(LinalgError::Singular), swap_blocks(&mut t, &mut q, 0, (2, 2)));
+        assert_eq!(equal, t);
+        assert_eq!(Matrix::identity(), q);
+
+        let b = Matrix::from([[1.0, 1.0], [0.0, 1.0 + d]]);
+        let (q, t, m) = b.schur_ordered(|re, _| re > 1.0).unwrap();
+        assert_schur(&b, &q, &t);
+        assert_eq!(1, m);
+        assert!((t[(0, 0)] - 1.0 - d).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,50 @@
+use super::Matrix;
+use crate::linalg::simd::SimdFloat;
+
+impl<T: SimdFloat, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// `add` through the SIMD kernels.
+    pub fn add_simd(self, rhs: Self) -> Self {
+        let mut m = Matrix::new();
+        T::simd_add(self.0.as_flattened(), rhs.0.as_flattened(), m.0.as_flattened_mut());
+        m
+    }
+    /// `mul_scal` through the SIMD kernels.
+    pub fn mul_scal_simd(&self, rhs: T) -> Self {
+        let mut m = Matrix::new();
+        T::simd_scale(self.0.as_flattened(), rhs, m.0.as_flattened_mut());
+        m
+    }
+    /// `mul` through the SIMD kernels.
+    ///
+    /// Every output row is accumulated as `row += a[i][k] * rhs[k]` in
+    /// increasing `k`, which is the summation order of `mul`, so both give
+    /// bit-identical results.
+    pub fn mul_simd<const K: usize>(self, rhs: Matrix<T, C, K>) -> Matrix<T, R, K> {
+        let mut m = Matrix::new();
+        T::simd_mul(&self.0, &rhs.0, &mut m.0);
+        m
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_mul_scal() {
+        let m1: Matrix<f64, 3, 5> = Matrix::from_fn(|i, j| (i * 5 + j) as f64 / 7.0);
+        let m2 = Matrix::from_fn(|i, j| (i + j) as f64 * 1.5);
+        assert_eq!(m1 + m2, m1.add_simd(m2));
+        assert_eq!(m1 * 3.0, m1.mul_scal_simd(3.0));
+    }
+    #[test]
+    fn mul_simd() {
+        let m1: Matrix<f32, 4, 6> = Matrix::from_fn(|i, j| (i * 6 + j) as f32 / 7.0);
+        let m2: Matrix<f32, 6, 9> = Matrix::from_fn(|i, j| (i as f32 - j as f32) * 0.3);
+        let serial = m1 * m2;
+        let simd = m1.mul_simd(m2);
+        assert!(serial.iter().zip(simd.iter()).all(|(x, y)| x.to_bits() == y.to_bits()));
+    }
+}
@@ -0,0 +1,164 @@
+use super::Matrix;
+use crate::linalg::scalar::{Float, One};
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Mul, Neg};
+
+/// `V[i][j] = x[i]^j`
+pub fn vandermonde<T, const R: usize, const C: usize>(x: &Vector<T, R>) -> Matrix<T, R, C>
+where
+    T: Default + One + Copy + Mul<Output = T>
+{
+    let mut m = Matrix::new();
+    for row in 0..R {
+        let mut power = T::one();
+        for col in 0..C {
+            m[(row, col)] = power;
+            power = power * x[row];
+        }
+    }
+    m
+}
+
+/// `H[i][j] = 1 / (i + j + 1)`
+pub fn hilbert<T: Float, const R: usize, const C: usize>() -> Matrix<T, R, C> {
+    Matrix::from_fn(|row, col| T::ONE / T::from_usize(row + col + 1))
+}
+
+/// Constant along the diagonals, `T[i][j] = col[i - j]` below and on the
+/// diagonal and `row[j - i]` above it. `row[0]` is ignored.
+pub fn toeplitz<T, const R: usize, const C: usize>(col: &Vector<T, R>, row: &Vector<T, C>) -> Matrix<T, R, C>
+where
+    T: Default + Copy
+{
+    Matrix::from_fn(|i, j| if i >= j { col[i - j] } else { row[j - i] })
+}
+
+/// Circulant matrix with first column `c`, `C[i][j] = c[(i - j) mod N]`.
+pub fn circulant<T, const N: usize>(c: &Vector<T, N>) -> Matrix<T, N, N>
+where
+    T: Default + Copy
+{
+    Matrix::from_fn(|i, j| c[(i + N - j) % N])
+}
+
+/// Constant along the anti-diagonals, with first column `col` and last row
+/// `row`. `row[0]` is ignored.
+pub fn hankel<T, const R: usize, const C: usize>(col: &Vector<T, R>, row: &Vector<T, C>) -> Matrix<T, R, C>
+where
+    T: Default + Copy
+{
+    Matrix::from_fn(|i, j| if i + j < R { col[i + j] } else { row[i + j + 1 - R] })
+}
+
+/// Companion matrix of the monic polynomial
+/// `x^N + c[N-1] x^(N-1) + ... + c[1] x + c[0]`, its eigenvalues are the
+/// roots of the polynomial.
+pub fn companion<T, const N: usize>(c: &Vector<T, N>) -> Matrix<T, N, N>
+where
+    T: Default + One + Copy + Neg<Output = T>
+{
+    let mut m = Matrix::new();
+    for i in 1..N {
+        m[(i, i - 1)] = T::one();
+    }
+    for i in 0..N {
+        m[(i, N - 1)] = -c[i];
+    }
+    m
+}
+
+/// Reflector `I - 2 v v^T / (v^T v)`, the identity for a zero `v`.
+pub fn householder<T: Float, const N: usize>(v: &Vector<T, N>) -> Matrix<T, N, N> {
+    let vv = *v * *v;
+    if vv == T::ZERO {
+        return Matrix::identity();
+    }
+    let scale = (T::ONE + T::ONE) / vv;
+    Matrix::from_fn(|i, j| {
+        let delta = if i == j { T::ONE } else { T::ZERO };
+        delta - scale * v[i] * v[j]
+    })
+}
+
+/// Rotation in the `(i, j)` plane, the identity except for
+/// `G[i][i] = G[j][j] = c`, `G[i][j] = s` and `G[j][i] = -s`.
+pub fn givens<T, const N: usize>(i: usize, j: usize, c: T, s: T) -> Matrix<T, N, N>
+where
+    T: Default + One + Copy + Neg<Output = T>
+{
+    let mut m = Matrix::identity();
+    m[(i, i)] = c;
+    m[(j, j)] = c;
+    m[(i, j)] = s;
+    m[(j, i)] = -s;
+    m
+}
+
+/// Tridiagonal `tridiag(-1, 2, -1)`, the second-difference discretization
+/// of `-d²/dx²` with Dirichlet boundaries. It is symmetric positive definite.
+pub fn second_difference<T, const N: usize>() -> Matrix<T, N, N>
+where
+    T: Default + One + Copy + Neg<Output = T> + Add<Output = T>
+{
+    let two = T::one() + T::one();
+    Matrix::from_fn(|i, j| {
+        if i == j {
+            two
+        } else if i + 1 == j || j + 1 == i {
+            -T::one()
+        } else {
+            T::default()
+        }
+    })
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vandermonde() {
+        let m: Matrix<_, 3, 3> = super::vandermonde(&Vector::from([1, 2, 3]));
+        assert_eq!(Matrix::from([[1, 1, 1], [1, 2, 4], [1, 3, 9]]), m);
+    }
+    #[test]
+    fn hilbert() {
+        let m: Matrix<f64, 2, 3> = super::hilbert();
+        assert_eq!(Matrix::from([[1.0, 1.0 / 2.0, 1.0 / 3.0], [1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0]]), m);
+    }
+    #[test]
+    fn toeplitz_circulant_hankel() {
+        let t = toeplitz(&Vector::from([1, 2, 3]), &Vector::from([0, 4, 5]));
+        assert_eq!(Matrix::from([[1, 4, 5], [2, 1, 4], [3, 2, 1]]), t);
+
+        let c = circulant(&Vector::from([1, 2, 3]));
+        assert_eq!(Matrix::from([[1, 3, 2], [2, 1, 3], [3, 2, 1]]), c);
+
+        let h = hankel(&Vector::from([1, 2, 3]), &Vector::from([0, 4]));
+        assert_eq!(Matrix::from([[1, 2], [2, 3], [3, 4]]), h);
+    }
+    #[test]
+    fn companion() {
+        // x^2 - 3x + 2 = (x - 1)(x - 2)
+        let m = super::companion(&Vector::from([2, -3]));
+        assert_eq!(Matrix::from([[0, -2], [1, 3]]), m);
+    }
+    #[test]
+    fn householder() {
+        let h = super::householder(&Vector::from([1.0, 1.0]));
+        assert!((h - Matrix::from([[0.0, -1.0], [-1.0, 0.0]])).frobenius_norm() < 1e-15);
+        assert!((h * h - Matrix::identity()).frobenius_norm() < 1e-15);
+    }
+    #[test]
+    fn givens() {
+        let g: Matrix<i32, 3, 3> = super::givens(0, 2, 0, 1);
+        assert_eq!(Matrix::from([[0, 0, 1], [0, 1, 0], [-1, 0, 0]]), g);
+    }
+    #[test]
+    fn second_difference() {
+        let m: Matrix<i32, 3, 3> = super::second_difference();
+        assert_eq!(Matrix::from([[2, -1, 0], [-1, 2, -1], [0, -1, 2]]), m);
+    }
+}
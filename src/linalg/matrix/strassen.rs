@@ -0,0 +1,130 @@
+use super::operations::mul_flat;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+const DEFAULT_THRESHOLD: usize = 64;
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Default + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+    /// Strassen product with the default cutoff of 64.
+    pub fn mul_strassen(self, rhs: Self) -> Self {
+        self.mul_strassen_with(rhs, DEFAULT_THRESHOLD)
+    }
+    /// Strassen product, falling back to the classical kernel once the
+    /// blocks are `threshold` wide or smaller.
+    ///
+    /// The operands are zero-padded to `n0 * 2^k`, where `k` halvings bring
+    /// `N` down to a leaf of `n0 <= threshold`. It needs
+    /// `O(n^2.81)` operations instead of `O(n^3)`, but the rounding error is
+    /// only bounded normwise,
+    /// `|C - Ĉ| <= ((n/n0)^log2(12) (n0² + 5 n0) - 5n) u |A| |B|`
+    /// with `n0` the leaf size and `u` the unit roundoff, while `mul` has
+    /// the componentwise bound `|C - Ĉ| <= n u |A| |B|`. The difference
+    /// shows on badly scaled operands. Integer products are exact.
+    pub fn mul_strassen_with(self, rhs: Self, threshold: usize) -> Self {
+        let threshold = threshold.max(1);
+        let mut levels = 0;
+        while N.div_ceil(1 << levels) > threshold {
+            levels += 1;
+        }
+        let size = N.div_ceil(1 << levels) << levels;
+        let pad = |m: &Matrix<T, N, N>| {
+            let mut data = vec![T::default(); size * size];
+            for (row, values) in m.0.iter().enumerate() {
+                data[row * size..row * size + N].copy_from_slice(values);
+            }
+            data
+        };
+
+        let c = strassen(&pad(&self), &pad(&rhs), size, threshold);
+        Matrix::from_fn(|row, col| c[row * size + col])
+    }
+}
+
+fn quadrants<T: Copy>(m: &[T], n: usize) -> [Vec<T>; 4] {
+    let h = n / 2;
+    let block = |row0: usize, col0: usize| {
+        (0..h).flat_map(|i| m[(row0 + i) * n + col0..(row0 + i) * n + col0 + h].iter().copied()).collect()
+    };
+    [block(0, 0), block(0, h), block(h, 0), block(h, h)]
+}
+
+fn zip_with<T: Copy>(a: &[T], b: &[T], f: impl Fn(T, T) -> T) -> Vec<T> {
+    a.iter().zip(b).map(|(&x, &y)| f(x, y)).collect()
+}
+
+// `n` is the leaf size times a power of two, so it is even above the
+// threshold
+fn strassen<T>(a: &[T], b: &[T], n: usize, threshold: usize) -> Vec<T>
+where
+    T: Default + Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+    if n <= threshold {
+        let mut c = vec![T::default(); n * n];
+        mul_flat(a, b, &mut c, n, n);
+        return c;
+    }
+    let h = n / 2;
+    let [a11, a12, a21, a22] = quadrants(a, n);
+    let [b11, b12, b21, b22] = quadrants(b, n);
+    let add = |x: &[T], y: &[T]| zip_with(x, y, |p, q| p + q);
+    let sub = |x: &[T], y: &[T]| zip_with(x, y, |p, q| p - q);
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), h, threshold);
+    let m2 = strassen(&add(&a21, &a22), &b11, h, threshold);
+    let m3 = strassen(&a11, &sub(&b12, &b22), h, threshold);
+    let m4 = strassen(&a22, &sub(&b21, &b11), h, threshold);
+    let m5 = strassen(&add(&a11, &a12), &b22, h, threshold);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), h, threshold);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), h, threshold);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = vec![T::default(); n * n];
+    for i in 0..h {
+        c[i * n..i * n + h].copy_from_slice(&c11[i * h..(i + 1) * h]);
+        c[i * n + h..(i + 1) * n].copy_from_slice(&c12[i * h..(i + 1) * h]);
+        c[(h + i) * n..(h + i) * n + h].copy_from_slice(&c21[i * h..(i + 1) * h]);
+        c[(h + i) * n + h..(h + i + 1) * n].copy_from_slice(&c22[i * h..(i + 1) * h]);
+    }
+    c
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_exact() {
+        let m1: Matrix<i64, 11, 11> = Matrix::from_fn(|i, j| (i * 11 + j) as i64 % 7 - 3);
+        let m2: Matrix<i64, 11, 11> = Matrix::from_fn(|i, j| (i + 2 * j) as i64 % 5 - 2);
+        for threshold in [1, 2, 4, 16] {
+            assert_eq!(m1 * m2, m1.mul_strassen_with(m2, threshold));
+        }
+    }
+    #[test]
+    fn odd_sizes() {
+        let m1: Matrix<i64, 37, 37> = Matrix::from_fn(|i, j| (i * 37 + j) as i64 % 9 - 4);
+        let m2: Matrix<i64, 37, 37> = Matrix::from_fn(|i, j| (3 * i + j) as i64 % 7 - 3);
+        for threshold in [3, 4, 5, 10, 19, 36, 37] {
+            assert_eq!(m1 * m2, m1.mul_strassen_with(m2, threshold));
+        }
+    }
+    #[test]
+    fn floats() {
+        let m1: Matrix<f64, 20, 20> = Matrix::from_fn(|i, j| ((i * 20 + j) as f64).sin());
+        let m2: Matrix<f64, 20, 20> = Matrix::from_fn(|i, j| ((i * 20 + j) as f64 * 0.7).cos());
+        let diff = (m1 * m2 - m1.mul_strassen_with(m2, 4)).frobenius_norm();
+        assert!(diff < 1e-12 * m1.frobenius_norm() * m2.frobenius_norm());
+        assert_eq!(m1 * m2, m1.mul_strassen(m2));
+    }
+}
@@ -0,0 +1,179 @@
+use super::schur::{block, block_size, solve_kronecker, solve_small_sylvester};
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+
+// `(start, size)` of the diagonal blocks of a quasi-triangular matrix
+fn blocks<T: Float, const N: usize>(t: &Matrix<T, N, N>) -> ([(usize, usize); N], usize) {
+    let mut list = [(0, 0); N];
+    let (mut k, mut count) = (0, 0);
+    while k < N {
+        let size = block_size(t, k);
+        list[count] = (k, size);
+        count += 1;
+        k += size;
+    }
+    (list, count)
+}
+
+impl<T: Float, const N: usize> Matrix<T, N, N> {
+    /// Solves the Sylvester equation `AX + XB = C` by the Bartels–Stewart
+    /// method.
+    ///
+    /// Both matrices are reduced to real Schur form and the transformed
+    /// equation is solved block by block. The solution is unique unless `A`
+    /// and `-B` share an eigenvalue, which is reported as `Singular`.
+    pub fn solve_sylvester<const M: usize>(
+        &self,
+        b: &Matrix<T, M, M>,
+        c: &Matrix<T, N, M>,
+    ) -> Result<Matrix<T, N, M>, LinalgError> {
+        let (u, s) = self.schur()?;
+        let (v, t) = b.schur()?;
+        let f = u.transpose() * *c * v;
+        let mut y: Matrix<T, N, M> = Matrix::new();
+
+        let (rows, row_count) = blocks(&s);
+        let (cols, col_count) = blocks(&t);
+        for &(j, q) in &cols[..col_count] {
+            for &(i, p) in rows[..row_count].iter().rev() {
+                let mut rhs = [[T::ZERO; 2]; 2];
+                for (r, rhs_row) in rhs.iter_mut().enumerate().take(p) {
+                    for (c, val) in rhs_row.iter_mut().enumerate().take(q) {
+                        let (row, col) = (i + r, j + c);
+                        let known = (i + p..N).fold(f[(row, col)], |acc, k| acc - s[(row, k)] * y[(k, col)]);
+                        *val = (0..j).fold(known, |acc, l| acc - y[(row, l)] * t[(l, col)]);
+                    }
+                }
+                let (s_ii, t_jj) = (block(&s, (i, i), (p, p)), block(&t, (j, j), (q, q)));
+                let (x, singular) = solve_small_sylvester((&s_ii, p), (&t_jj, q), &rhs);
+                if singular {
+                    return Err(LinalgError::Singular);
+                }
+                for (r, x_row) in x.iter().enumerate().take(p) {
+                    for (c, &val) in x_row.iter().enumerate().take(q) {
+                        y[(i + r, j + c)] = val;
+                    }
+                }
+            }
+        }
+        Ok(u * y * v.transpose())
+    }
+
+    /// Solves the continuous Lyapunov equation `AX + XAᵀ + Q = 0`, see
+    /// [`Matrix::solve_sylvester`].
+    pub fn solve_lyapunov(&self, q: &Self) -> Result<Self, LinalgError> {
+        self.solve_sylvester(&self.transpose(), &(*q * -T::ONE))
+    }
+
+    /// Solves the discrete Lyapunov (Stein) equation `AXAᵀ - X + Q = 0`.
+    ///
+    /// With `A = USUᵀ` in real Schur form, `SYSᵀ - Y = -UᵀQU` is solved block
+    /// by block from the bottom right. The solution is unique unless two
+    /// eigenvalues of `A` multiply to one, which is reported as `Singular`.
+    pub fn solve_discrete_lyapunov(&self, q: &Self) -> Result<Self, LinalgError> {
+        let (u, s) = self.schur()?;
+        let f = u.transpose() * *q * u;
+        let mut y: Self = Matrix::new();
+        // rows of Y Sᵀ, filled in once a block row of Y is complete
+        let mut w: Self = Matrix::new();
+
+        let (list, count) = blocks(&s);
+        for &(i, p) in list[..count].iter().rev() {
+            for &(j, q) in list[..count].iter().rev() {
+                let mut rhs = [[T::ZERO; 2]; 2];
+                for (r, rhs_row) in rhs.iter_mut().enumerate().take(p) {
+                    for (c, val) in rhs_row.iter_mut().enumerate().take(q) {
+                        let (row, col) = (i + r, j + c);
+                        let mut sum = (i + p..N).fold(T::ZERO, |acc, k| acc + s[(row, k)] * w[(k, col)]);
+                        for k in i..i + p {
+                            let partial = (j + q..N).fold(T::ZERO, |acc, l| acc + y[(k, l)] * s[(col, l)]);
+                            sum = sum + s[(row, k)] * partial;
+                        }
+                        *val = -f[(row, col)] - sum;
+                    }
+                }
+
+                // Kronecker form of S_ii Y S_jjᵀ - Y
+                let (a, b) = (block(&s, (i, i), (p, p)), block(&s, (j, j), (q, q)));
+                let mut k = [[T::ZERO; 4]; 4];
+                for r in 0..p {
+                    for c in 0..q {
+                        for m in 0..p {
+                            for n in 0..q {
+                                k[r * q + c][m * q + n] = a[r][m] * b[c][n];
+                            }
+                        }
+                        k[r * q + c][r * q + c] = k[r * q + c][r * q + c] - T::ONE;
+                    }
+                }
+                let (x, singular) = solve_kronecker(k, &rhs, (p, q));
+                if singular {
+                    return Err(LinalgError::Singular);
+                }
+                for (r, x_row) in x.iter().enumerate().take(p) {
+                    for (c, &val) in x_row.iter().enumerate().take(q) {
+                        y[(i + r, j + c)] = val;
+                    }
+                }
+            }
+            for row in i..i + p {
+                for col in 0..N {
+                    let first = col.saturating_sub(1);
+                    w[(row, col)] = (first..N).fold(T::ZERO, |acc, l| acc + y[(row, l)] * s[(col, l)]);
+                }
+            }
+        }
+        Ok(u * y * u.transpose())
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // eigenvalues -1 ± 2i and -3
+    fn stable() -> Matrix<f64, 3, 3> {
+        Matrix::from([[-1.0, 2.0, 0.5], [-2.0, -1.0, 1.0], [0.0, 0.0, -3.0]])
+    }
+
+    #[test]
+    fn sylvester() {
+        let a = stable();
+        let b = Matrix::from([[2.0, 1.0], [0.0, 5.0]]);
+        let c = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let x = a.solve_sylvester(&b, &c).unwrap();
+        assert!((a * x + x * b - c).frobenius_norm() < 1e-10);
+
+        let b = Matrix::from([[0.0, 3.0], [-3.0, 1.0]]);
+        let x = a.solve_sylvester(&b, &c).unwrap();
+        assert!((a * x + x * b - c).frobenius_norm() < 1e-10);
+    }
+    #[test]
+    fn sylvester_singular() {
+        let a = Matrix::from([[1.0, 0.0], [0.0, 2.0]]);
+        let b = Matrix::from([[-2.0]]);
+        let c = Matrix::from([[1.0], [1.0]]);
+        assert_eq!(Err(LinalgError::Singular), a.solve_sylvester(&b, &c));
+    }
+    #[test]
+    fn lyapunov() {
+        let a = stable();
+        let q = Matrix::identity();
+        let x = a.solve_lyapunov(&q).unwrap();
+        assert!((a * x + x * a.transpose() + q).frobenius_norm() < 1e-10);
+        assert!((x - x.transpose()).frobenius_norm() < 1e-10);
+    }
+    #[test]
+    fn discrete_lyapunov() {
+        let a = stable() * 0.25;
+        let q = Matrix::from([[2.0, 1.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 2.0]]);
+        let x = a.solve_discrete_lyapunov(&q).unwrap();
+        assert!((a * x * a.transpose() - x + q).frobenius_norm() < 1e-10);
+
+        let a = Matrix::from([[0.5, 0.0], [0.0, 2.0]]);
+        assert_eq!(Err(LinalgError::Singular), a.solve_discrete_lyapunov(&Matrix::identity()));
+    }
+}
@@ -0,0 +1,129 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Index, IndexMut, Mul};
+
+/// Symmetric matrix that stores only the upper triangle, packed row by row
+/// into `N * (N + 1) / 2` elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<T, const N: usize> {
+    data: Vec<T>,
+}
+
+impl<T, const N: usize> SymmetricMatrix<T, N> {
+    fn offset((row, col): (usize, usize)) -> usize {
+        let (row, col) = if row <= col { (row, col) } else { (col, row) };
+        assert!(col < N, "index out of bounds");
+        row * N - row * (row + 1) / 2 + col
+    }
+    pub fn get(&self, (row, col): (usize, usize)) -> &T {
+        &self.data[Self::offset((row, col))]
+    }
+    /// Mutable access to the entry, which is shared with its mirror `(col, row)`.
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        &mut self.data[Self::offset((row, col))]
+    }
+    pub fn set(&mut self, (row, col): (usize, usize), value: T) {
+        *self.get_mut((row, col)) = value;
+    }
+}
+
+impl<T: Default, const N: usize> SymmetricMatrix<T, N> {
+    pub fn new() -> Self {
+        SymmetricMatrix { data: (0..N * (N + 1) / 2).map(|_| T::default()).collect() }
+    }
+    /// Keeps the upper triangle of `m`, the lower one is assumed to mirror it.
+    pub fn from_matrix(m: Matrix<T, N, N>) -> Self {
+        let data = m.into_iter()
+            .enumerate()
+            .filter(|((row, col), _)| row <= col)
+            .map(|(_, v)| v)
+            .collect();
+        SymmetricMatrix { data }
+    }
+}
+
+impl<T: Default, const N: usize> Default for SymmetricMatrix<T, N> {
+    fn default() -> Self {
+        SymmetricMatrix::new()
+    }
+}
+
+impl<T: Default + Copy, const N: usize> SymmetricMatrix<T, N> {
+    pub fn to_matrix(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|row, col| *self.get((row, col)))
+    }
+}
+
+impl<T, const N: usize> SymmetricMatrix<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    pub fn mul_vector(&self, rhs: &Vector<T, N>) -> Vector<T, N> {
+        let mut y = Vector::from_fn(|_| T::default());
+        let mut k = 0;
+        for row in 0..N {
+            y[row] = y[row] + self.data[k] * rhs[row];
+            k += 1;
+            for col in row + 1..N {
+                let v = self.data[k];
+                y[row] = y[row] + v * rhs[col];
+                y[col] = y[col] + v * rhs[row];
+                k += 1;
+            }
+        }
+        y
+    }
+}
+
+impl<T, const N: usize> Mul<Vector<T, N>> for &SymmetricMatrix<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    type Output = Vector<T, N>;
+
+    fn mul(self, rhs: Vector<T, N>) -> Self::Output {
+        self.mul_vector(&rhs)
+    }
+}
+
+impl<T, const N: usize> Index<(usize, usize)> for SymmetricMatrix<T, N> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index)
+    }
+}
+impl<T, const N: usize> IndexMut<(usize, usize)> for SymmetricMatrix<T, N> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed() {
+        let m = Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+        let s = SymmetricMatrix::from_matrix(m);
+        assert_eq!(6, s.data.len());
+        assert_eq!(5, s[(2, 1)]);
+        assert_eq!(m, s.to_matrix());
+    }
+    #[test]
+    fn set_mirrors() {
+        let mut s: SymmetricMatrix<i32, 2> = SymmetricMatrix::new();
+        s[(1, 0)] = 7;
+        assert_eq!(Matrix::from([[0, 7], [7, 0]]), s.to_matrix());
+    }
+    #[test]
+    fn mul_vector() {
+        let m = Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+        let v = Vector::from([1, -1, 2]);
+        assert_eq!(m * v, &SymmetricMatrix::from_matrix(m) * v);
+    }
+}
@@ -0,0 +1,263 @@
+use super::Matrix;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::{Float, One};
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Mul};
+
+/// Upper triangular matrix, the entries below the diagonal are always zero.
+#[derive(Debug)]
+pub struct UpperTriangular<T: Default, const N: usize>(Matrix<T, N, N>);
+
+/// Lower triangular matrix, the entries above the diagonal are always zero.
+#[derive(Debug)]
+pub struct LowerTriangular<T: Default, const N: usize>(Matrix<T, N, N>);
+
+impl<T: Default, const N: usize> UpperTriangular<T, N> {
+    /// Keeps the upper triangle of `m` and drops the rest.
+    pub fn from_matrix(m: Matrix<T, N, N>) -> Self {
+        let mut m = m;
+        for row in 1..N {
+            for col in 0..row {
+                m[(row, col)] = T::default();
+            }
+        }
+        UpperTriangular(m)
+    }
+    pub fn as_matrix(&self) -> &Matrix<T, N, N> {
+        &self.0
+    }
+    pub fn into_matrix(self) -> Matrix<T, N, N> {
+        self.0
+    }
+    pub fn get(&self, (row, col): (usize, usize)) -> &T {
+        self.0.get((row, col))
+    }
+    pub fn transpose(self) -> LowerTriangular<T, N> {
+        LowerTriangular(self.0.transpose())
+    }
+}
+
+impl<T: Default, const N: usize> LowerTriangular<T, N> {
+    /// Keeps the lower triangle of `m` and drops the rest.
+    pub fn from_matrix(m: Matrix<T, N, N>) -> Self {
+        let mut m = m;
+        for row in 0..N {
+            for col in row + 1..N {
+                m[(row, col)] = T::default();
+            }
+        }
+        LowerTriangular(m)
+    }
+    pub fn as_matrix(&self) -> &Matrix<T, N, N> {
+        &self.0
+    }
+    pub fn into_matrix(self) -> Matrix<T, N, N> {
+        self.0
+    }
+    pub fn get(&self, (row, col): (usize, usize)) -> &T {
+        self.0.get((row, col))
+    }
+    pub fn transpose(self) -> UpperTriangular<T, N> {
+        UpperTriangular(self.0.transpose())
+    }
+}
+
+impl<T: Copy + Default, const N: usize> Copy for UpperTriangular<T, N> {}
+impl<T: Copy + Default, const N: usize> Clone for UpperTriangular<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: Copy + Default, const N: usize> Copy for LowerTriangular<T, N> {}
+impl<T: Copy + Default, const N: usize> Clone for LowerTriangular<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> UpperTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T> + One
+{
+    /// Product of the diagonal.
+    pub fn determinant(&self) -> T {
+        (0..N).fold(T::one(), |acc, i| acc * self.0[(i, i)])
+    }
+}
+
+impl<T, const N: usize> UpperTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    /// Dense product that only visits the nonzero upper half.
+    pub fn mul_matrix<const K: usize>(&self, rhs: &Matrix<T, N, K>) -> Matrix<T, N, K> {
+        let mut m = Matrix::new();
+        for row in 0..N {
+            for col in 0..K {
+                m[(row, col)] = (row..N).fold(T::default(), |acc, j| acc + self.0[(row, j)] * rhs[(j, col)]);
+            }
+        }
+        m
+    }
+    pub fn mul_vector(&self, rhs: &Vector<T, N>) -> Vector<T, N> {
+        Vector::from_fn(|row| (row..N).fold(T::default(), |acc, j| acc + self.0[(row, j)] * rhs[j]))
+    }
+}
+
+impl<T, const N: usize> LowerTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T> + One
+{
+    /// Product of the diagonal.
+    pub fn determinant(&self) -> T {
+        (0..N).fold(T::one(), |acc, i| acc * self.0[(i, i)])
+    }
+}
+
+impl<T, const N: usize> LowerTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    /// Dense product that only visits the nonzero lower half.
+    pub fn mul_matrix<const K: usize>(&self, rhs: &Matrix<T, N, K>) -> Matrix<T, N, K> {
+        let mut m = Matrix::new();
+        for row in 0..N {
+            for col in 0..K {
+                m[(row, col)] = (0..=row).fold(T::default(), |acc, j| acc + self.0[(row, j)] * rhs[(j, col)]);
+            }
+        }
+        m
+    }
+    pub fn mul_vector(&self, rhs: &Vector<T, N>) -> Vector<T, N> {
+        Vector::from_fn(|row| (0..=row).fold(T::default(), |acc, j| acc + self.0[(row, j)] * rhs[j]))
+    }
+}
+
+impl<T, const N: usize, const K: usize> Mul<Matrix<T, N, K>> for UpperTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    type Output = Matrix<T, N, K>;
+
+    fn mul(self, rhs: Matrix<T, N, K>) -> Self::Output {
+        self.mul_matrix(&rhs)
+    }
+}
+impl<T, const N: usize, const K: usize> Mul<Matrix<T, N, K>> for LowerTriangular<T, N>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    type Output = Matrix<T, N, K>;
+
+    fn mul(self, rhs: Matrix<T, N, K>) -> Self::Output {
+        self.mul_matrix(&rhs)
+    }
+}
+
+impl<T: Float, const N: usize> UpperTriangular<T, N> {
+    /// Solves `Ux = b` by back substitution.
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let mut x = *b;
+        for row in (0..N).rev() {
+            let pivot = self.0[(row, row)];
+            if pivot == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            let sum = (row + 1..N).fold(x[row], |acc, j| acc - self.0[(row, j)] * x[j]);
+            x[row] = sum / pivot;
+        }
+        Ok(x)
+    }
+    /// Solves `UX = B` column by column.
+    pub fn solve_matrix<const K: usize>(&self, b: &Matrix<T, N, K>) -> Result<Matrix<T, N, K>, LinalgError> {
+        let mut x = *b;
+        for row in (0..N).rev() {
+            let pivot = self.0[(row, row)];
+            if pivot == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            for col in 0..K {
+                let sum = (row + 1..N).fold(x[(row, col)], |acc, j| acc - self.0[(row, j)] * x[(j, col)]);
+                x[(row, col)] = sum / pivot;
+            }
+        }
+        Ok(x)
+    }
+}
+
+impl<T: Float, const N: usize> LowerTriangular<T, N> {
+    /// Solves `Lx = b` by forward substitution.
+    pub fn solve(&self, b: &Vector<T, N>) -> Result<Vector<T, N>, LinalgError> {
+        let mut x = *b;
+        for row in 0..N {
+            let pivot = self.0[(row, row)];
+            if pivot == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            let sum = (0..row).fold(x[row], |acc, j| acc - self.0[(row, j)] * x[j]);
+            x[row] = sum / pivot;
+        }
+        Ok(x)
+    }
+    /// Solves `LX = B` column by column.
+    pub fn solve_matrix<const K: usize>(&self, b: &Matrix<T, N, K>) -> Result<Matrix<T, N, K>, LinalgError> {
+        let mut x = *b;
+        for row in 0..N {
+            let pivot = self.0[(row, row)];
+            if pivot == T::ZERO {
+                return Err(LinalgError::Singular);
+            }
+            for col in 0..K {
+                let sum = (0..row).fold(x[(row, col)], |acc, j| acc - self.0[(row, j)] * x[(j, col)]);
+                x[(row, col)] = sum / pivot;
+            }
+        }
+        Ok(x)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_matrix() {
+        let m = Matrix::from([[1, 2], [3, 4]]);
+        assert_eq!(&Matrix::from([[1, 2], [0, 4]]), UpperTriangular::from_matrix(m).as_matrix());
+        assert_eq!(&Matrix::from([[1, 0], [3, 4]]), LowerTriangular::from_matrix(m).as_matrix());
+    }
+    #[test]
+    fn determinant() {
+        let u = UpperTriangular::from_matrix(Matrix::from([[2, 7, 1], [0, 3, 5], [0, 0, 4]]));
+        assert_eq!(24, u.determinant());
+        assert_eq!(24, u.transpose().determinant());
+    }
+    #[test]
+    fn mul() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let rhs = Matrix::from([[1, 0], [2, 1], [0, 3]]);
+        let u = UpperTriangular::from_matrix(m);
+        let l = LowerTriangular::from_matrix(m);
+        assert_eq!(*u.as_matrix() * rhs, u * rhs);
+        assert_eq!(*l.as_matrix() * rhs, l * rhs);
+        assert_eq!(Vector::from([14, 28, 27]), u.mul_vector(&Vector::from([1, 2, 3])));
+    }
+    #[test]
+    fn solve() {
+        let u = UpperTriangular::from_matrix(Matrix::from([[2.0, 1.0], [0.0, 4.0]]));
+        assert_eq!(Vector::from([1.0, 2.0]), u.solve(&Vector::from([4.0, 8.0])).unwrap());
+        let l = u.transpose();
+        assert_eq!(Vector::from([2.0, 1.5]), l.solve(&Vector::from([4.0, 8.0])).unwrap());
+
+        let b = Matrix::from([[4.0, 2.0], [8.0, 4.0]]);
+        let x = u.solve_matrix(&b).unwrap();
+        assert_eq!(b, u * x);
+    }
+    #[test]
+    fn solve_singular() {
+        let l = LowerTriangular::from_matrix(Matrix::from([[1.0, 0.0], [1.0, 0.0]]));
+        assert_eq!(Err(LinalgError::Singular), l.solve(&Vector::from([1.0, 1.0])));
+    }
+}
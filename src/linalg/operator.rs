@@ -0,0 +1,69 @@
+use crate::linalg::matrix::Matrix;
+use crate::linalg::vector::Vector;
+use core::ops::{Add, Mul};
+
+/// Linear map from `C` to `R` dimensions, known only through its action on
+/// vectors.
+///
+/// Implemented for `Matrix` and for closures `Fn(&Vector<T, C>) -> Vector<T, R>`,
+/// so matrix-free operators can be passed wherever a matrix is accepted.
+pub trait LinearOperator<T, const R: usize, const C: usize> {
+    fn apply(&self, x: &Vector<T, C>) -> Vector<T, R>;
+
+    /// `Aᵀy`, if the operator supports it.
+    fn apply_transpose(&self, _y: &Vector<T, R>) -> Option<Vector<T, C>> {
+        None
+    }
+
+    /// `(rows, cols)`.
+    fn dims(&self) -> (usize, usize) {
+        (R, C)
+    }
+}
+
+impl<T, const R: usize, const C: usize> LinearOperator<T, R, C> for Matrix<T, R, C>
+where
+    T: Default + Copy + Add<Output = T> + Mul<Output = T>
+{
+    fn apply(&self, x: &Vector<T, C>) -> Vector<T, R> {
+        self.mul_vector(x)
+    }
+    fn apply_transpose(&self, y: &Vector<T, R>) -> Option<Vector<T, C>> {
+        Some(self.transpose().mul_vector(y))
+    }
+}
+
+impl<T, F, const R: usize, const C: usize> LinearOperator<T, R, C> for F
+where
+    F: Fn(&Vector<T, C>) -> Vector<T, R>
+{
+    fn apply(&self, x: &Vector<T, C>) -> Vector<T, R> {
+        self(x)
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_twice<A: LinearOperator<i32, 2, 2>>(a: &A, x: &Vector<i32, 2>) -> Vector<i32, 2> {
+        a.apply(&a.apply(x))
+    }
+
+    #[test]
+    fn matrix() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(Vector::from([14, 32]), m.apply(&Vector::from([1, 2, 3])));
+        assert_eq!(Some(Vector::from([9, 12, 15])), m.apply_transpose(&Vector::from([1, 2])));
+        assert_eq!((2, 3), LinearOperator::dims(&m));
+    }
+    #[test]
+    fn closure() {
+        let swap = |x: &Vector<i32, 2>| Vector::from([x[1], x[0]]);
+        assert_eq!(Vector::from([2, 1]), swap.apply(&Vector::from([1, 2])));
+        assert_eq!(Vector::from([1, 2]), apply_twice(&swap, &Vector::from([1, 2])));
+        assert_eq!(None, swap.apply_transpose(&Vector::from([1, 2])));
+    }
+}
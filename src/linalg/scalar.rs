@@ -0,0 +1,154 @@
+use core::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use super::vector::operations::Sqrt;
+
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_one {
+    ($one:literal; $($t:ty),*) => {
+        $(
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+impl_one!(1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_one!(1.0; f32, f64);
+
+pub trait Float:
+    One
+    + Copy
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Sqrt<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const EPSILON: Self;
+
+    fn abs(self) -> Self;
+    fn from_usize(n: usize) -> Self;
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Float for $t {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                const EPSILON: Self = <$t>::EPSILON;
+
+                fn abs(self) -> Self {
+                    if self < 0.0 { -self } else { self }
+                }
+                fn from_usize(n: usize) -> Self {
+                    n as $t
+                }
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+impl_float!(f32, f64);
+
+/// Signed integers, for exact algorithms that detect overflow instead of
+/// wrapping.
+pub trait Integer:
+    One
+    + Copy
+    + Default
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_integer!(i8, i16, i32, i64, i128, isize);
+
+// Software square root for builds without `std`. Newton's iteration starting
+// from a guess with the exponent halved, which is within a few percent, so a
+// handful of steps reach the last bit.
+macro_rules! soft_sqrt {
+    ($name:ident, $t:ty, $half_bias:expr) => {
+        #[allow(dead_code)]
+        pub(crate) fn $name(x: $t) -> $t {
+            if x.is_nan() || x < 0.0 {
+                return <$t>::NAN;
+            }
+            if x == 0.0 || x == <$t>::INFINITY {
+                return x;
+            }
+            let mut y = <$t>::from_bits((x.to_bits() >> 1) + $half_bias);
+            for _ in 0..64 {
+                let next = 0.5 * (y + x / y);
+                if next == y {
+                    break;
+                }
+                y = next;
+            }
+            y
+        }
+    };
+}
+soft_sqrt!(soft_sqrt_f64, f64, 0x3FF << 51);
+soft_sqrt!(soft_sqrt_f32, f32, 0x7F << 22);
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soft_sqrt() {
+        assert!((soft_sqrt_f64(2.0) - core::f64::consts::SQRT_2).abs() <= f64::EPSILON);
+        assert!((soft_sqrt_f32(2.0) - core::f32::consts::SQRT_2).abs() <= f32::EPSILON);
+        for x in [1e-300, 1e-5, 0.25, 3.0, 1e10, 1e300] {
+            let y = soft_sqrt_f64(x);
+            assert!((y * y - x).abs() <= 4.0 * f64::EPSILON * x);
+        }
+        assert_eq!(0.0, soft_sqrt_f64(0.0));
+        assert_eq!(f64::INFINITY, soft_sqrt_f64(f64::INFINITY));
+        assert!(soft_sqrt_f64(-1.0).is_nan());
+    }
+}
@@ -0,0 +1,264 @@
+use super::scalar::Float;
+
+/// Slice kernels with explicit SIMD implementations, picked at runtime from
+/// the features of the CPU. All slices must have the same length.
+pub trait SimdFloat: Float {
+    fn simd_dot(a: &[Self], b: &[Self]) -> Self;
+    /// `out = a + b`
+    fn simd_add(a: &[Self], b: &[Self], out: &mut [Self]);
+    /// `out = a * s`
+    fn simd_scale(a: &[Self], s: Self, out: &mut [Self]);
+    /// `out = out + s * a`, without fused multiply-add
+    fn simd_axpy(s: Self, a: &[Self], out: &mut [Self]);
+    /// `out = a * b` for the rows of `a` and `out`. Every output row is
+    /// accumulated as `row += a[i][k] * b[k]` in increasing `k`, and the CPU
+    /// features are only checked once for the whole product.
+    fn simd_mul<const C: usize, const K: usize>(a: &[[Self; C]], b: &[[Self; K]; C], out: &mut [[Self; K]]);
+}
+
+/// Portable versions of the kernels, used when no SIMD extension is available.
+pub mod scalar {
+    use super::Float;
+
+    pub fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+        assert_eq!(a.len(), b.len());
+        a.iter().zip(b).fold(T::ZERO, |acc, (&x, &y)| acc + x * y)
+    }
+    pub fn add<T: Float>(a: &[T], b: &[T], out: &mut [T]) {
+        assert!(a.len() == b.len() && a.len() == out.len());
+        for ((o, &x), &y) in out.iter_mut().zip(a).zip(b) {
+            *o = x + y;
+        }
+    }
+    pub fn scale<T: Float>(a: &[T], s: T, out: &mut [T]) {
+        assert_eq!(a.len(), out.len());
+        for (o, &x) in out.iter_mut().zip(a) {
+            *o = x * s;
+        }
+    }
+    pub fn axpy<T: Float>(s: T, a: &[T], out: &mut [T]) {
+        assert_eq!(a.len(), out.len());
+        for (o, &x) in out.iter_mut().zip(a) {
+            *o = *o + s * x;
+        }
+    }
+    pub fn mul<T: Float, const C: usize, const K: usize>(a: &[[T; C]], b: &[[T; K]; C], out: &mut [[T; K]]) {
+        assert_eq!(a.len(), out.len());
+        for (row, out_row) in a.iter().zip(out.iter_mut()) {
+            out_row.fill(T::ZERO);
+            for (&s, b_row) in row.iter().zip(b) {
+                axpy(s, b_row, out_row);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx {
+    use core::arch::x86_64::*;
+
+    macro_rules! avx_kernels {
+        ($t:ty, $lanes:literal, $dot:ident, $add:ident, $scale:ident, $axpy:ident, $mul:ident,
+         $loadu:ident, $storeu:ident, $addv:ident, $mulv:ident, $set1:ident, $zero:ident) => {
+            #[target_feature(enable = "avx")]
+            pub unsafe fn $dot(a: &[$t], b: &[$t]) -> $t {
+                assert_eq!(a.len(), b.len());
+                let n = a.len() - a.len() % $lanes;
+                let mut acc = $zero();
+                for i in (0..n).step_by($lanes) {
+                    let x = $loadu(a.as_ptr().add(i));
+                    let y = $loadu(b.as_ptr().add(i));
+                    acc = $addv(acc, $mulv(x, y));
+                }
+                let mut lanes = [0.0; $lanes];
+                $storeu(lanes.as_mut_ptr(), acc);
+                let sum = lanes.iter().sum::<$t>();
+                a[n..].iter().zip(&b[n..]).fold(sum, |acc, (&x, &y)| acc + x * y)
+            }
+            #[target_feature(enable = "avx")]
+            pub unsafe fn $add(a: &[$t], b: &[$t], out: &mut [$t]) {
+                assert!(a.len() == b.len() && a.len() == out.len());
+                let n = a.len() - a.len() % $lanes;
+                for i in (0..n).step_by($lanes) {
+                    let x = $loadu(a.as_ptr().add(i));
+                    let y = $loadu(b.as_ptr().add(i));
+                    $storeu(out.as_mut_ptr().add(i), $addv(x, y));
+                }
+                for i in n..a.len() {
+                    out[i] = a[i] + b[i];
+                }
+            }
+            #[target_feature(enable = "avx")]
+            pub unsafe fn $scale(a: &[$t], s: $t, out: &mut [$t]) {
+                assert_eq!(a.len(), out.len());
+                let n = a.len() - a.len() % $lanes;
+                let sv = $set1(s);
+                for i in (0..n).step_by($lanes) {
+                    let x = $loadu(a.as_ptr().add(i));
+                    $storeu(out.as_mut_ptr().add(i), $mulv(x, sv));
+                }
+                for i in n..a.len() {
+                    out[i] = a[i] * s;
+                }
+            }
+            #[target_feature(enable = "avx")]
+            pub unsafe fn $axpy(s: $t, a: &[$t], out: &mut [$t]) {
+                assert_eq!(a.len(), out.len());
+                let n = a.len() - a.len() % $lanes;
+                let sv = $set1(s);
+                for i in (0..n).step_by($lanes) {
+                    let x = $loadu(a.as_ptr().add(i));
+                    let o = $loadu(out.as_ptr().add(i));
+                    $storeu(out.as_mut_ptr().add(i), $addv(o, $mulv(sv, x)));
+                }
+                for i in n..a.len() {
+                    out[i] += s * a[i];
+                }
+            }
+            #[target_feature(enable = "avx")]
+            pub unsafe fn $mul<const C: usize, const K: usize>(
+                a: &[[$t; C]],
+                b: &[[$t; K]; C],
+                out: &mut [[$t; K]],
+            ) {
+                assert_eq!(a.len(), out.len());
+                for (row, out_row) in a.iter().zip(out.iter_mut()) {
+                    out_row.fill(0.0);
+                    for (&s, b_row) in row.iter().zip(b) {
+                        $axpy(s, b_row, out_row);
+                    }
+                }
+            }
+        };
+    }
+
+    avx_kernels!(f64, 4, dot_f64, add_f64, scale_f64, axpy_f64, mul_f64,
+        _mm256_loadu_pd, _mm256_storeu_pd, _mm256_add_pd, _mm256_mul_pd, _mm256_set1_pd, _mm256_setzero_pd);
+    avx_kernels!(f32, 8, dot_f32, add_f32, scale_f32, axpy_f32, mul_f32,
+        _mm256_loadu_ps, _mm256_storeu_ps, _mm256_add_ps, _mm256_mul_ps, _mm256_set1_ps, _mm256_setzero_ps);
+}
+
+// Without `std` there is no runtime detection, so AVX is only used when it is
+// enabled at compile time, e.g. with `-C target-feature=+avx`.
+#[cfg(target_arch = "x86_64")]
+fn has_avx() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx")
+    }
+}
+
+macro_rules! impl_simd_float {
+    ($t:ty, $dot:ident, $add:ident, $scale:ident, $axpy:ident, $mul:ident) => {
+        impl SimdFloat for $t {
+            fn simd_dot(a: &[$t], b: &[$t]) -> $t {
+                #[cfg(target_arch = "x86_64")]
+                if has_avx() {
+                    // SAFETY: AVX was detected at runtime, and the kernel
+                    // asserts that the slice lengths are equal.
+                    return unsafe { avx::$dot(a, b) };
+                }
+                scalar::dot(a, b)
+            }
+            fn simd_add(a: &[$t], b: &[$t], out: &mut [$t]) {
+                #[cfg(target_arch = "x86_64")]
+                if has_avx() {
+                    // SAFETY: AVX was detected at runtime, and the kernel
+                    // asserts that the slice lengths are equal.
+                    return unsafe { avx::$add(a, b, out) };
+                }
+                scalar::add(a, b, out)
+            }
+            fn simd_scale(a: &[$t], s: $t, out: &mut [$t]) {
+                #[cfg(target_arch = "x86_64")]
+                if has_avx() {
+                    // SAFETY: AVX was detected at runtime, and the kernel
+                    // asserts that the slice lengths are equal.
+                    return unsafe { avx::$scale(a, s, out) };
+                }
+                scalar::scale(a, s, out)
+            }
+            fn simd_axpy(s: $t, a: &[$t], out: &mut [$t]) {
+                #[cfg(target_arch = "x86_64")]
+                if has_avx() {
+                    // SAFETY: AVX was detected at runtime, and the kernel
+                    // asserts that the slice lengths are equal.
+                    return unsafe { avx::$axpy(s, a, out) };
+                }
+                scalar::axpy(s, a, out)
+            }
+            fn simd_mul<const C: usize, const K: usize>(a: &[[$t; C]], b: &[[$t; K]; C], out: &mut [[$t; K]]) {
+                #[cfg(target_arch = "x86_64")]
+                if has_avx() {
+                    // SAFETY: AVX was detected at runtime, and the kernel
+                    // asserts that `a` and `out` have the same number of rows.
+                    return unsafe { avx::$mul(a, b, out) };
+                }
+                scalar::mul(a, b, out)
+            }
+        }
+    };
+}
+impl_simd_float!(f64, dot_f64, add_f64, scale_f64, axpy_f64, mul_f64);
+impl_simd_float!(f32, dot_f32, add_f32, scale_f32, axpy_f32, mul_f32);
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data<T: Float>(shift: usize) -> [T; 65] {
+        core::array::from_fn(|i| T::from_usize((i * 7 + shift) % 11) / T::from_usize(3))
+    }
+
+    #[test]
+    fn paths_agree_f64() {
+        for n in [0, 1, 3, 4, 9, 33] {
+            let (a, b) = (&data::<f64>(0)[..n], &data::<f64>(5)[..n]);
+            assert!((f64::simd_dot(a, b) - scalar::dot(a, b)).abs() < 1e-12);
+
+            let (simd, portable) = (&mut [0.0; 65][..n], &mut [0.0; 65][..n]);
+            f64::simd_add(a, b, simd);
+            scalar::add(a, b, portable);
+            assert_eq!(portable, simd);
+
+            f64::simd_scale(a, 1.5, simd);
+            scalar::scale(a, 1.5, portable);
+            assert_eq!(portable, simd);
+
+            f64::simd_axpy(0.25, b, simd);
+            scalar::axpy(0.25, b, portable);
+            assert_eq!(portable, simd);
+        }
+    }
+    #[test]
+    fn paths_agree_f32() {
+        for n in [0, 1, 7, 8, 17, 65] {
+            let (a, b) = (&data::<f32>(0)[..n], &data::<f32>(5)[..n]);
+            assert!((f32::simd_dot(a, b) - scalar::dot(a, b)).abs() < 1e-3);
+
+            let (simd, portable) = (&mut [0.0; 65][..n], &mut [0.0; 65][..n]);
+            f32::simd_add(a, b, simd);
+            scalar::add(a, b, portable);
+            assert_eq!(portable, simd);
+
+            f32::simd_axpy(0.25, b, simd);
+            scalar::axpy(0.25, b, portable);
+            assert_eq!(portable, simd);
+        }
+    }
+    #[test]
+    fn mul_paths_agree() {
+        let a: [[f64; 7]; 3] = core::array::from_fn(|i| core::array::from_fn(|k| data::<f64>(i)[k]));
+        let b: [[f64; 9]; 7] = core::array::from_fn(|k| core::array::from_fn(|j| data::<f64>(k + 3)[j]));
+        let (mut simd, mut portable) = ([[1.0; 9]; 3], [[2.0; 9]; 3]);
+        f64::simd_mul(&a, &b, &mut simd);
+        scalar::mul(&a, &b, &mut portable);
+        assert_eq!(portable, simd);
+    }
+}
@@ -1,13 +1,20 @@
 pub mod indexing;
 pub mod operations;
 pub mod iterators;
+pub mod orthogonal;
+pub mod simd;
 
-#[derive(Debug)]
+use super::scalar::One;
+
+#[derive(Debug, PartialEq)]
 pub struct Vector<T, const L: usize>([T; L]);
 impl<T, const L: usize> Vector<T, L> {
-    pub fn from(data: [T; L]) -> Self {
+    pub const fn from(data: [T; L]) -> Self {
         Vector( data )
     }
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        Vector( core::array::from_fn(f) )
+    }
     pub fn get(&self, index: usize) -> &T {
         &self.0[index]
     }
@@ -23,10 +30,10 @@ impl<T, const L: usize> Vector<T, L> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
         self.0.iter()
     }
-    pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
         self.0.iter_mut()
     }
 }
@@ -38,11 +45,21 @@ where
         Vector::from( [value; L] )
     }
 }
+impl<T, const L: usize> Vector<T, L>
+where
+    T: Default + One
+{
+    pub fn unit(index: usize) -> Self {
+        let mut v = Vector::from_fn(|_| T::default());
+        v[index] = T::one();
+        v
+    }
+}
 
 impl<T: Copy, const L: usize> Copy for Vector<T, L> {}
 impl<T: Copy, const L: usize> Clone for Vector<T, L> {
     fn clone(&self) -> Self {
-       Vector::from(self.0)
+        *self
     }
 }
 
@@ -72,4 +89,14 @@ mod tests {
         let v2: Vector<i32, 1> = Vector::from_value(1);
         assert_eq!(v1.len(), v2.len());
     }
+    #[test]
+    fn from_fn() {
+        let v = Vector::from_fn(|i| i * 2);
+        assert_eq!(Vector::from([0, 2, 4]), v);
+    }
+    #[test]
+    fn unit() {
+        let v: Vector<i32, 3> = Vector::unit(1);
+        assert_eq!(Vector::from([0, 1, 0]), v);
+    }
 }
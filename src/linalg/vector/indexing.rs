@@ -1,5 +1,5 @@
 use super::Vector;
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 
 impl<T, const L: usize> Index<usize> for Vector<T, L> {
     type Output = T;
@@ -13,7 +13,7 @@ impl<T, const L: usize> IndexMut<usize> for Vector<T, L> {
     }
 }
 
-use std::ops::Range;
+use core::ops::Range;
 impl<T, const L: usize> Index<Range<usize>> for Vector<T, L> {
     type Output = [T];
 
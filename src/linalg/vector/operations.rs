@@ -10,14 +10,28 @@ impl Sqrt for f64 {
     type Output = f64;
 
     fn sqrt(val: f64) -> Self::Output {
-        Self::sqrt(val)
+        #[cfg(feature = "std")]
+        {
+            val.sqrt()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            crate::linalg::scalar::soft_sqrt_f64(val)
+        }
     }
 }
 impl Sqrt for f32 {
     type Output = f32;
 
     fn sqrt(val: f32) -> Self::Output {
-        Self::sqrt(val)
+        #[cfg(feature = "std")]
+        {
+            val.sqrt()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            crate::linalg::scalar::soft_sqrt_f32(val)
+        }
     }
 }
 
@@ -31,7 +45,7 @@ where
     pub fn dist(&self, rhs: &Self) -> T {
         T::sqrt(
             self.into_iter()
-                .zip( rhs.into_iter() )
+                .zip( *rhs )
                 .fold( T::default(), |mut acc, (v1, v2)| { 
                     let diff = v1 - v2;
                     acc = acc + diff * diff;
@@ -41,15 +55,16 @@ where
     }
 }
 
-use std::ops::{Mul, Add, Sub, Div};
+use core::ops::{Mul, Add, Sub, Div};
 
 impl<T, const L: usize> Vector<T, L>
 where
     T: Add<Output = T>
 {
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, rhs: Self) -> Self {
         self.into_iter()
-            .zip(rhs.into_iter())
+            .zip(rhs)
             .map(|(v1, v2)| v1 + v2)
             .collect()
     }
@@ -68,9 +83,10 @@ impl<T, const L: usize> Vector<T, L>
 where
     T: Sub<Output = T>
 {
+    #[allow(clippy::should_implement_trait)]
     pub fn sub(self, rhs: Self) -> Self {
         self.into_iter()
-            .zip(rhs.into_iter())
+            .zip(rhs)
             .map(|(v1, v2)| v1 - v2)
             .collect()
     }
@@ -112,7 +128,7 @@ where
 {
     pub fn dot(self, rhs: Self) -> T {
         self.into_iter()
-            .zip(rhs.into_iter())
+            .zip(rhs)
             .fold(T::default(), |mut acc, (v1, v2)| { acc = acc + v1 * v2; acc } )
     }
 }
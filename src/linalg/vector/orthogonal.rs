@@ -0,0 +1,107 @@
+use super::Vector;
+use crate::linalg::error::LinalgError;
+use crate::linalg::scalar::Float;
+
+/// Orthonormalizes `vectors` in place with modified Gram–Schmidt.
+///
+/// A vector that is linearly dependent on the ones before it is replaced by
+/// the zero vector and the remaining ones are still processed, so the
+/// nonzero entries always form an orthonormal set. In that case the number
+/// of independent vectors is reported through `LinalgError::RankDeficient`.
+///
+/// Every vector is orthogonalized twice, which keeps the result orthogonal
+/// to working precision, and counts as dependent when less than `√ε` of its
+/// norm is left. At most `L` vectors are kept.
+pub fn gram_schmidt<T: Float, const L: usize>(vectors: &mut [Vector<T, L>]) -> Result<(), LinalgError> {
+    let tol = T::sqrt(T::EPSILON);
+    let mut rank = 0;
+
+    for i in 0..vectors.len() {
+        let (basis, rest) = vectors.split_at_mut(i);
+        let v = &mut rest[0];
+        let original = v.norm();
+
+        for _ in 0..2 {
+            for q in basis.iter() {
+                *v = *v - q.mul_scal(*q * *v);
+            }
+        }
+
+        let norm = v.norm();
+        if rank == L || norm == T::ZERO || norm <= tol * original {
+            *v = Vector::from_value(T::ZERO);
+        } else {
+            *v = v.div_scal(norm);
+            rank += 1;
+        }
+    }
+
+    if rank < vectors.len() {
+        Err(LinalgError::RankDeficient { rank })
+    } else {
+        Ok(())
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orthonormal() {
+        let mut vs = [
+            Vector::from([1.0, 1.0, 0.0]),
+            Vector::from([1.0, 0.0, 1.0]),
+            Vector::from([0.0, 1.0, 1.0]),
+        ];
+        assert_eq!(Ok(()), gram_schmidt(&mut vs));
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((vs[i] * vs[j] - expected).abs() < 1e-12);
+            }
+        }
+    }
+    #[test]
+    fn first_vector_is_normalized() {
+        let mut vs = [Vector::from([3.0, 4.0]), Vector::from([1.0, 0.0])];
+        gram_schmidt(&mut vs).unwrap();
+        assert!((vs[0][0] - 0.6).abs() < 1e-12);
+        assert!((vs[0][1] - 0.8).abs() < 1e-12);
+    }
+    #[test]
+    fn rank_deficient() {
+        let mut vs = [
+            Vector::from([1.0, 2.0, 3.0]),
+            Vector::from([2.0, 4.0, 6.0]),
+            Vector::from([0.0, 0.0, 1.0]),
+        ];
+        assert_eq!(Err(LinalgError::RankDeficient { rank: 2 }), gram_schmidt(&mut vs));
+        assert_eq!(0.0, vs[1].norm());
+        assert!((vs[0] * vs[2]).abs() < 1e-12);
+    }
+    #[test]
+    fn more_vectors_than_dimension() {
+        // xorshift, so the sets are in general position
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        for _ in 0..1000 {
+            let mut vs: [Vector<f64, 3>; 4] = core::array::from_fn(|_| Vector::from_fn(|_| next()));
+            assert_eq!(Err(LinalgError::RankDeficient { rank: 3 }), gram_schmidt(&mut vs));
+            assert_eq!(0.0, vs[3].norm());
+            for i in 0..3 {
+                for j in 0..3 {
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!((vs[i] * vs[j] - expected).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use super::Vector;
+use crate::linalg::simd::SimdFloat;
+
+impl<T: SimdFloat, const L: usize> Vector<T, L> {
+    /// `dot` through the SIMD kernels.
+    ///
+    /// The sum is split across the SIMD lanes, so unlike `add_simd` the
+    /// result can differ from `dot` in the last bits.
+    pub fn dot_simd(self, rhs: Self) -> T {
+        T::simd_dot(&self.0, &rhs.0)
+    }
+    /// `add` through the SIMD kernels.
+    pub fn add_simd(self, rhs: Self) -> Self {
+        let mut v = Vector::from_value(T::ZERO);
+        T::simd_add(&self.0, &rhs.0, &mut v.0);
+        v
+    }
+}
+
+// unit tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dot_simd() {
+        let v1 = Vector::from_fn(|i| i as f64 * 0.5);
+        let v2 = Vector::from_fn(|i| 10.0 - i as f64);
+        let v: Vector<f64, 11> = v1;
+        assert!((v.dot_simd(v2) - v1.dot(v2)).abs() < 1e-12);
+    }
+    #[test]
+    fn add_simd() {
+        let v1: Vector<f32, 13> = Vector::from_fn(|i| i as f32 * 0.5);
+        let v2 = Vector::from_fn(|i| 10.0 - i as f32);
+        assert_eq!(v1 + v2, v1.add_simd(v2));
+    }
+}